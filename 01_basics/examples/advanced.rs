@@ -173,6 +173,20 @@ impl FilePermissions {
     }
 }
 
+/// Three ways to handle integer overflow explicitly
+/// Shows: saturating_*, wrapping_*, checked_* methods on primitive integers
+pub fn add_saturating(a: u8, b: u8) -> u8 {
+    a.saturating_add(b)
+}
+
+pub fn add_wrapping(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+pub fn add_checked(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,15 +232,28 @@ mod tests {
         assert!(evaluate_expr("").is_err());
     }
 
+    #[test]
+    fn test_overflow_strategies() {
+        assert_eq!(add_saturating(200, 100), 255);
+        assert_eq!(add_wrapping(200, 100), 44); // 300 - 256
+        assert_eq!(add_checked(200, 100), None);
+
+        // No overflow: all three agree
+        assert_eq!(add_saturating(20, 30), 50);
+        assert_eq!(add_wrapping(20, 30), 50);
+        assert_eq!(add_checked(20, 30), Some(50));
+    }
+
     #[test]
     fn test_file_permissions() {
-        let mut perms = FilePermissions::from_octical(0o755);
+        let mut perms = FilePermissions::from_octal(0o755);
         assert!(perms.owner_can_read());
         assert!(perms.owner & WRITE != 0);
         assert!(perms.owner & EXECUTE != 0);
         
         perms.set_owner_permission(WRITE, false);
-        assert!(!perms.owner_can_read());  // Wait, this checks READ not WRITE
+        assert!(perms.owner & WRITE == 0);
+        assert!(perms.owner_can_read());
         assert_eq!(perms.to_octal(), 0o555);
     }
 }
\ No newline at end of file
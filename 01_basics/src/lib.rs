@@ -29,8 +29,17 @@ pub fn swap(a: i32, b: i32) -> (i32, i32) {
 /// π ใช้ค่า std::f64::consts::PI
 pub fn circle_area() -> f64 {
     let r = 2.5;
-    // TODO: คำนวณพื้นที่
-    0.0
+    circle_area_of(r)
+}
+
+/// คำนวณพื้นที่วงกลม (πr²) จากรัศมีที่รับเข้ามา
+pub fn circle_area_of(radius: f64) -> f64 {
+    std::f64::consts::PI * radius * radius
+}
+
+/// คำนวณปริมาตรทรงกลม (4/3 πr³)
+pub fn sphere_volume(radius: f64) -> f64 {
+    4.0 / 3.0 * std::f64::consts::PI * radius.powi(3)
 }
 
 /// คืนค่าตัวอักษรแรกและตัวเลขจาก tuple
@@ -56,6 +65,34 @@ pub fn max_of_two(a: i32, b: i32) -> i32 {
     0
 }
 
+/// คืนค่าที่มากที่สุดในสามค่า (ไม่ใช้ std max)
+pub fn max_of_three(a: i32, b: i32, c: i32) -> i32 {
+    let mut largest = a;
+    if b > largest {
+        largest = b;
+    }
+    if c > largest {
+        largest = c;
+    }
+    largest
+}
+
+/// คืนค่าที่น้อยกว่าระหว่าง a และ b (ไม่ใช้ std min)
+pub fn min_of_two(a: i32, b: i32) -> i32 {
+    if a < b { a } else { b }
+}
+
+/// บีบ x ให้อยู่ในช่วง [lo, hi] (สมมติว่า lo <= hi)
+pub fn clamp_value(x: i32, lo: i32, hi: i32) -> i32 {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+
 /// คำนวณ factorial (n!) 
 /// 0! = 1, 1! = 1, 2! = 2, 3! = 6, ...
 pub fn factorial(n: u32) -> u32 {
@@ -72,6 +109,29 @@ pub fn is_prime(n: u32) -> bool {
     true
 }
 
+/// ตรวจสอบจำนวนเฉพาะแบบ 6k±1 สำหรับ u64
+/// จำนวนเฉพาะที่มากกว่า 3 ทุกตัวอยู่ในรูป 6k-1 หรือ 6k+1
+/// จึงเช็คแค่ 2, 3 แล้วไล่หารด้วย i และ i+2 โดยเพิ่ม i ทีละ 6 จนถึง √n
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n < 4 {
+        return true;
+    }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) {
+        return false;
+    }
+    let mut i: u64 = 5;
+    while i <= n / i {
+        if n.is_multiple_of(i) || n.is_multiple_of(i + 2) {
+            return false;
+        }
+        i += 6;
+    }
+    true
+}
+
 // ============================================
 // EXERCISE 4: Control Flow - FizzBuzz
 // ============================================
@@ -91,23 +151,75 @@ pub fn fizzbuzz(n: i32) -> String {
 // ============================================
 
 /// คืนค่าผลรวมของเลข 1 ถึง n โดยใช้ for loop
+/// ระวัง: ผลรวมเกิน i32::MAX เมื่อ n > 65535
+/// (debug build จะ panic, release build จะ wrap เป็นค่าผิด)
 pub fn sum_to_n(n: i32) -> i32 {
     // TODO: ใช้ for loop คำนวณผลรวม
     0
 }
 
+/// ผลรวม 1 ถึง n ด้วยสูตร n(n+1)/2 ใน u64
+/// ไม่ overflow สำหรับทุกค่า n ที่เป็น u32
+pub fn sum_to_n_u64(n: u32) -> u64 {
+    let n = n as u64;
+    n * (n + 1) / 2
+}
+
 /// หาเลข fibonacci ตัวที่ n (0, 1, 1, 2, 3, 5, 8, ...)
 pub fn fibonacci(n: u32) -> u32 {
     // TODO: หา fibonacci
     0
 }
 
+/// คืน fibonacci `count` ตัวแรก เช่น count = 7 → [0, 1, 1, 2, 3, 5, 8]
+/// u64 เก็บได้ถึง 94 ตัวแรก (ตัวที่ 95 จะ overflow)
+pub fn fibonacci_sequence(count: usize) -> Vec<u64> {
+    let mut seq: Vec<u64> = Vec::with_capacity(count);
+    for i in 0..count {
+        let next = if i < 2 { i as u64 } else { seq[i - 1] + seq[i - 2] };
+        seq.push(next);
+    }
+    seq
+}
+
 /// นับจำนวนตัวอักษรในสตริง (ไม่รับช่องว่าง)
 pub fn count_chars(s: &str) -> usize {
     // TODO: นับตัวอักษรที่ไม่ใช่ช่องว่าง
     0
 }
 
+/// นับตัวอักษร (Unicode code point) ที่ไม่ใช่ whitespace
+/// `char::is_whitespace` ครอบคลุม tab, newline และ non-breaking space ด้วย
+/// ไม่ใช่แค่ช่องว่าง ASCII
+pub fn count_non_whitespace(s: &str) -> usize {
+    s.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+/// แยกเลขฐานสิบออกเป็นหลัก จากหลักซ้ายสุดไปขวาสุด
+/// digits(0) คืน [0]
+pub fn digits(n: u32) -> Vec<u8> {
+    let mut result = Vec::new();
+    let mut n = n;
+    loop {
+        result.push((n % 10) as u8);
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    result.reverse();
+    result
+}
+
+/// ผลรวมของทุกหลัก เช่น 1234 → 1+2+3+4 = 10
+pub fn digit_sum(n: u32) -> u32 {
+    let mut sum = 0;
+    for d in digits(n) {
+        sum += d as u32;
+    }
+    sum
+}
+
 // ============================================
 // TESTS
 // ============================================
@@ -132,6 +244,18 @@ mod tests {
         assert!((result - 19.6349).abs() < 0.001);
     }
 
+    #[test]
+    fn test_circle_area_of() {
+        assert!((circle_area_of(1.0) - std::f64::consts::PI).abs() < 1e-10);
+        assert_eq!(circle_area_of(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_sphere_volume() {
+        assert!((sphere_volume(1.0) - 4.18879).abs() < 0.00001);
+        assert!((sphere_volume(2.0) - 8.0 * sphere_volume(1.0)).abs() < 1e-10);
+    }
+
     #[test]
     fn test_get_first_char_and_number() {
         assert_eq!(get_first_char_and_number(('A', 42, true)), ('A', 42));
@@ -149,6 +273,30 @@ mod tests {
         assert_eq!(max_of_two(5, 5), 5);
     }
 
+    #[test]
+    fn test_max_of_three() {
+        assert_eq!(max_of_three(1, 2, 3), 3);
+        assert_eq!(max_of_three(3, 2, 1), 3);
+        assert_eq!(max_of_three(1, 3, 2), 3);
+        assert_eq!(max_of_three(-5, -5, -7), -5);
+    }
+
+    #[test]
+    fn test_min_of_two() {
+        assert_eq!(min_of_two(5, 10), 5);
+        assert_eq!(min_of_two(20, 10), 10);
+        assert_eq!(min_of_two(-3, -3), -3);
+    }
+
+    #[test]
+    fn test_clamp_value() {
+        assert_eq!(clamp_value(-5, 0, 10), 0);  // ต่ำกว่าช่วง
+        assert_eq!(clamp_value(5, 0, 10), 5);   // อยู่ในช่วง
+        assert_eq!(clamp_value(15, 0, 10), 10); // สูงกว่าช่วง
+        assert_eq!(clamp_value(0, 0, 10), 0);
+        assert_eq!(clamp_value(10, 0, 10), 10);
+    }
+
     #[test]
     fn test_factorial() {
         assert_eq!(factorial(0), 1);
@@ -166,6 +314,17 @@ mod tests {
         assert_eq!(is_prime(18), false);
     }
 
+    #[test]
+    fn test_is_prime_u64() {
+        let primes: Vec<u64> = (0..50).filter(|&n| is_prime_u64(n)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]);
+
+        assert!(is_prime_u64(1_000_000_007));
+        assert!(is_prime_u64(999_999_999_989)); // จำนวนเฉพาะที่มากที่สุดที่น้อยกว่า 10^12
+        assert!(!is_prime_u64(1_000_000_007 * 1_000_003));
+        assert!(!is_prime_u64(25));
+    }
+
     #[test]
     fn test_fizzbuzz() {
         assert_eq!(fizzbuzz(1), "1");
@@ -181,6 +340,14 @@ mod tests {
         assert_eq!(sum_to_n(10), 55);
     }
 
+    #[test]
+    fn test_sum_to_n_u64() {
+        assert_eq!(sum_to_n_u64(0), 0);
+        assert_eq!(sum_to_n_u64(10), 55);
+        assert_eq!(sum_to_n_u64(100_000), 5_000_050_000); // เกิน i32::MAX
+        assert_eq!(sum_to_n_u64(u32::MAX), 9_223_372_034_707_292_160);
+    }
+
     #[test]
     fn test_fibonacci() {
         assert_eq!(fibonacci(0), 0);
@@ -189,10 +356,44 @@ mod tests {
         assert_eq!(fibonacci(10), 55);
     }
 
+    #[test]
+    fn test_fibonacci_sequence() {
+        assert_eq!(fibonacci_sequence(0), Vec::<u64>::new());
+        assert_eq!(fibonacci_sequence(1), vec![0]);
+        assert_eq!(fibonacci_sequence(7), vec![0, 1, 1, 2, 3, 5, 8]);
+        assert_eq!(fibonacci_sequence(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!(fibonacci_sequence(94)[93], 12_200_160_415_121_876_738);
+    }
+
     #[test]
     fn test_count_chars() {
         assert_eq!(count_chars("hello"), 5);
         assert_eq!(count_chars("hello world"), 10); // ไม่นับช่องว่าง
         assert_eq!(count_chars("  rust  "), 4);
     }
+
+    #[test]
+    fn test_count_non_whitespace() {
+        assert_eq!(count_non_whitespace("hello world"), 10);
+        assert_eq!(count_non_whitespace("a\tb\nc"), 3);
+        assert_eq!(count_non_whitespace("a\u{00A0}b"), 2); // non-breaking space
+        assert_eq!(count_non_whitespace("สวัสดี ครับ"), 10);
+        assert_eq!(count_non_whitespace(" \t\n"), 0);
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(1234), vec![1, 2, 3, 4]);
+        assert_eq!(digits(0), vec![0]);
+        assert_eq!(digits(7), vec![7]);
+        assert_eq!(digits(1000), vec![1, 0, 0, 0]);
+        assert_eq!(digits(u32::MAX), vec![4, 2, 9, 4, 9, 6, 7, 2, 9, 5]);
+    }
+
+    #[test]
+    fn test_digit_sum() {
+        assert_eq!(digit_sum(1234), 10);
+        assert_eq!(digit_sum(0), 0);
+        assert_eq!(digit_sum(999), 27);
+    }
 }
@@ -73,6 +73,60 @@ pub fn substring(s: &str, start: usize, end: usize) -> &str {
     ""
 }
 
+/// คืนคำแรกไม่เกิน n คำ เป็น slice ที่ยืมมาจาก s
+/// ถ้ามีคำน้อยกว่า n ก็คืนเท่าที่มี
+pub fn first_n_words(s: &str, n: usize) -> Vec<&str> {
+    s.split_whitespace().take(n).collect()
+}
+
+/// คืนช่วง byte (start, end) ของแต่ละคำที่คั่นด้วย whitespace
+/// เก็บเป็น index แทน &str เพื่อไม่ต้องผูก lifetime กับ s
+/// ใช้ `&s[start..end]` เพื่อดึงคำออกมา
+pub fn word_positions(s: &str) -> Vec<(usize, usize)> {
+    let mut positions = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(st)) => {
+                positions.push((st, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(st) = start {
+        positions.push((st, s.len()));
+    }
+    positions
+}
+
+/// จัดคำให้แต่ละบรรทัดยาวไม่เกิน `width` ตัวอักษร (แบบ greedy)
+/// คำที่ยาวกว่า `width` อยู่บรรทัดของตัวเองโดยไม่ถูกตัด
+/// คืนเป็น String เพราะแต่ละบรรทัดต่อคำใหม่ ไม่ใช่ slice ของ text
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        if !current.is_empty() && current_len + 1 + word_len > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
 // ============================================
 // EXERCISE 5: Multiple Borrows
 // ============================================
@@ -94,6 +148,7 @@ pub fn find_min_max(numbers: &[i32]) -> Option<(i32, i32)> {
 // EXERCISE 6: Ownership with Structs
 // ============================================
 
+#[derive(Debug, PartialEq)]
 pub struct Person {
     name: String,
     age: u32,
@@ -119,6 +174,46 @@ impl Person {
     pub fn have_birthday(&mut self) {
         // TODO: เพิ่มอายุ
     }
+
+    /// สร้าง Person พร้อมชื่อและอายุ (copy ชื่อจาก &str มาเป็น String ของตัวเอง)
+    pub fn with_age(name: &str, age: u32) -> Person {
+        Person {
+            name: name.to_string(),
+            age,
+        }
+    }
+
+    /// อายุ 18 ปีขึ้นไปถือว่าเป็นผู้ใหญ่
+    pub fn is_adult(&self) -> bool {
+        self.age >= 18
+    }
+
+    /// คืนอายุ (u32 เป็น Copy จึงคืนค่าได้เลยไม่ต้องยืม)
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+
+    /// เพิ่มอายุทีละหลายปี ต้องยืมแบบ &mut
+    pub fn add_years(&mut self, years: u32) {
+        self.age += years;
+    }
+}
+
+/// เพิ่มอายุทุกคนใน slice ทีละ `years` ปี
+/// `iter_mut` ให้ &mut Person ทีละตัวโดยไม่ย้าย ownership ออกจาก slice
+pub fn age_everyone(people: &mut [Person], years: u32) {
+    for person in people.iter_mut() {
+        person.add_years(years);
+    }
+}
+
+/// อายุเฉลี่ย หรือ None ถ้าไม่มีใครเลย
+pub fn average_age(people: &[Person]) -> Option<f64> {
+    if people.is_empty() {
+        return None;
+    }
+    let total: u64 = people.iter().map(|p| p.age() as u64).sum();
+    Some(total as f64 / people.len() as f64)
 }
 
 // ============================================
@@ -178,6 +273,36 @@ mod tests {
         assert_eq!(substring("hello", 10, 20), ""); // out of bounds
     }
 
+    #[test]
+    fn test_first_n_words() {
+        assert_eq!(first_n_words("a b c d", 2), vec!["a", "b"]);
+        assert_eq!(first_n_words("a b c d", 10), vec!["a", "b", "c", "d"]);
+        assert_eq!(first_n_words("  spaced   out  ", 5), vec!["spaced", "out"]);
+        assert!(first_n_words("a b", 0).is_empty());
+    }
+
+    #[test]
+    fn test_word_positions() {
+        let s = "  hi  bye ";
+        let positions = word_positions(s);
+        assert_eq!(positions, vec![(2, 4), (6, 9)]);
+
+        let words: Vec<&str> = positions.iter().map(|&(start, end)| &s[start..end]).collect();
+        assert_eq!(words, vec!["hi", "bye"]);
+
+        assert_eq!(word_positions("สวัสดี rust"), vec![(0, 18), (19, 23)]);
+        assert!(word_positions("   ").is_empty());
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("the quick brown fox", 9), vec!["the quick", "brown fox"]);
+        assert_eq!(wrap_text("the quick brown fox", 100), vec!["the quick brown fox"]);
+        assert_eq!(wrap_text("a extraordinary b", 5), vec!["a", "extraordinary", "b"]);
+        assert_eq!(wrap_text("ก ข ค", 3), vec!["ก ข", "ค"]);
+        assert!(wrap_text("   ", 10).is_empty());
+    }
+
     #[test]
     fn test_sum_and_average() {
         let nums = [1, 2, 3, 4, 5];
@@ -200,4 +325,32 @@ mod tests {
         person.have_birthday();
         assert_eq!(person.age, 26);
     }
+
+    #[test]
+    fn test_person_is_adult() {
+        assert!(!Person::with_age("Teen", 17).is_adult());
+        assert!(Person::with_age("Grown", 18).is_adult());
+    }
+
+    #[test]
+    fn test_age_everyone_and_average() {
+        let mut people = vec![Person::with_age("Alice", 30), Person::with_age("Bob", 21)];
+        assert_eq!(average_age(&people), Some(25.5));
+
+        age_everyone(&mut people, 2);
+        assert_eq!(people[0].age(), 32);
+        assert_eq!(people[1].age(), 23);
+        assert_eq!(average_age(&people), Some(27.5));
+
+        assert_eq!(average_age(&[]), None);
+    }
+
+    #[test]
+    fn test_person_equality() {
+        let a = Person::with_age("Alice", 30);
+        assert_eq!(a, Person::with_age("Alice", 30));
+        assert_ne!(a, Person::with_age("Alice", 31));
+        assert_ne!(a, Person::with_age("Bob", 30));
+        assert_eq!(format!("{:?}", a), r#"Person { name: "Alice", age: 30 }"#);
+    }
 }
@@ -59,7 +59,7 @@ impl Machine<Stopped> {
 
 /// Never type pattern for unrepresentable states
 /// Shows: ! type (never), exhaustive matching
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum JsonValue {
     Null,
     Bool(bool),
@@ -77,6 +77,38 @@ impl JsonValue {
         }
     }
 
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Walk a dotted path such as `"items.0.name"`.
+    /// Each segment is looked up against the current value: on an object it is
+    /// a key (so `"0"` can still name an object field), on an array it must
+    /// parse as a zero-based index. Anything else yields `None`.
     pub fn get_path(&self, path: &str) -> Option<&JsonValue> {
         let mut current = self;
         for key in path.split('.') {
@@ -84,6 +116,9 @@ impl JsonValue {
                 JsonValue::Object(map) => {
                     current = map.get(key)?;
                 }
+                JsonValue::Array(items) => {
+                    current = items.get(key.parse::<usize>().ok()?)?;
+                }
                 _ => return None,
             }
         }
@@ -118,6 +153,263 @@ impl fmt::Display for JsonValue {
     }
 }
 
+/// Where and why `parse_json` gave up
+#[derive(Debug, PartialEq)]
+pub struct JsonParseError {
+    /// Byte offset into the input
+    pub position: usize,
+    pub reason: &'static str,
+}
+
+impl JsonParseError {
+    fn new(position: usize, reason: &'static str) -> Self {
+        JsonParseError { position, reason }
+    }
+}
+
+impl fmt::Display for JsonParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.reason, self.position)
+    }
+}
+
+impl std::error::Error for JsonParseError {}
+
+/// Recursive-descent JSON parser
+/// Shows: building a recursive enum from text, one method per grammar rule
+pub fn parse_json(input: &str) -> Result<JsonValue, JsonParseError> {
+    let mut parser = JsonParser { input, pos: 0, depth: 0 };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+/// Deepest array/object nesting parse_json accepts; deeper input is an error
+/// instead of overflowing the stack
+const MAX_JSON_DEPTH: usize = 128;
+
+struct JsonParser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn error(&self, reason: &'static str) -> JsonParseError {
+        JsonParseError::new(self.pos, reason)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8, reason: &'static str) -> Result<(), JsonParseError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.parse_literal("null", JsonValue::Null),
+            Some(b't') => self.parse_literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.parse_literal("false", JsonValue::Bool(false)),
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b'[') => self.parse_nested(Self::parse_array),
+            Some(b'{') => self.parse_nested(Self::parse_object),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected token")),
+        }
+    }
+
+    /// Runs an array/object rule one level deeper, failing past MAX_JSON_DEPTH
+    fn parse_nested(
+        &mut self,
+        rule: fn(&mut Self) -> Result<JsonValue, JsonParseError>,
+    ) -> Result<JsonValue, JsonParseError> {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        self.depth += 1;
+        let value = rule(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, JsonParseError> {
+        if self.input[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error("unexpected token"))
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, JsonParseError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        if self.skip_digits() == 0 {
+            return Err(self.error("invalid number"));
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.skip_digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        self.input[start..self.pos]
+            .parse()
+            .map(JsonValue::Number)
+            .map_err(|_| JsonParseError::new(start, "invalid number"))
+    }
+
+    /// Expects to be positioned on the opening quote
+    fn parse_string(&mut self) -> Result<String, JsonParseError> {
+        self.expect(b'"', "expected string")?;
+        let mut out = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+
+        while let Some((offset, c)) = chars.next() {
+            let here = self.pos + offset;
+            match c {
+                '"' => {
+                    self.pos = here + 1;
+                    return Ok(out);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'u')) => {
+                            let hex: String = chars.by_ref().take(4).map(|(_, h)| h).collect();
+                            u32::from_str_radix(&hex, 16)
+                                .ok()
+                                .filter(|_| hex.len() == 4)
+                                .and_then(char::from_u32)
+                                .ok_or(JsonParseError::new(here, "invalid unicode escape"))?
+                        }
+                        Some(_) => return Err(JsonParseError::new(here, "invalid escape")),
+                        None => break,
+                    };
+                    out.push(escaped);
+                }
+                c if (c as u32) < 0x20 => {
+                    return Err(JsonParseError::new(here, "control character in string"));
+                }
+                c => out.push(c),
+            }
+        }
+
+        self.pos = self.input.len();
+        Err(self.error("unterminated string"))
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonParseError> {
+        self.expect(b'[', "expected '['")?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    if self.peek() == Some(b']') {
+                        return Err(self.error("trailing comma"));
+                    }
+                }
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                None => return Err(self.error("unexpected end of input")),
+                Some(_) => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonParseError> {
+        self.expect(b'{', "expected '{'")?;
+        let mut map = HashMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(map));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b':', "expected ':'")?;
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    if self.peek() == Some(b'}') {
+                        return Err(self.error("trailing comma"));
+                    }
+                }
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(map));
+                }
+                None => return Err(self.error("unexpected end of input")),
+                Some(_) => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+}
+
 /// ThisError-style error handling
 /// Shows: enums with data, #[derive(Debug)]
 #[derive(Debug)]
@@ -267,6 +559,114 @@ mod tests {
         assert!(display.contains("Alice"));
     }
 
+    #[test]
+    fn test_json_accessors() {
+        assert_eq!(JsonValue::Number(2.5).as_f64(), Some(2.5));
+        assert_eq!(JsonValue::Bool(true).as_bool(), Some(true));
+        assert_eq!(JsonValue::Null.as_f64(), None);
+        assert_eq!(JsonValue::Number(1.0).as_bool(), None);
+        assert_eq!(JsonValue::String("x".to_string()).as_array(), None);
+        assert!(JsonValue::Array(vec![]).as_object().is_none());
+
+        let array = JsonValue::Array(vec![JsonValue::Null, JsonValue::Bool(false)]);
+        assert_eq!(array.as_array().map(|items| items.len()), Some(2));
+        assert!(parse_json("{}").unwrap().as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_path_with_array_indices() {
+        let doc = parse_json(
+            r#"{"items": [{"name": "pen", "price": 1.5}, {"name": "ink", "tags": ["blue"]}],
+                "0": {"ok": true}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(doc.get_path("items.0.name").and_then(|v| v.as_string()), Some("pen"));
+        assert_eq!(doc.get_path("items.0.price").and_then(|v| v.as_f64()), Some(1.5));
+        assert_eq!(doc.get_path("items.1.tags.0").and_then(|v| v.as_string()), Some("blue"));
+        assert_eq!(doc.get_path("0.ok").and_then(|v| v.as_bool()), Some(true));
+
+        assert!(doc.get_path("items.2").is_none());
+        assert!(doc.get_path("items.first").is_none());
+        assert!(doc.get_path("items.0.name.0").is_none());
+    }
+
+    #[test]
+    fn test_parse_json_scalars() {
+        assert_eq!(parse_json("null"), Ok(JsonValue::Null));
+        assert_eq!(parse_json(" true "), Ok(JsonValue::Bool(true)));
+        assert_eq!(parse_json("false"), Ok(JsonValue::Bool(false)));
+        assert_eq!(parse_json("-12.5e2"), Ok(JsonValue::Number(-1250.0)));
+        assert_eq!(parse_json("0"), Ok(JsonValue::Number(0.0)));
+        assert_eq!(
+            parse_json(r#""a\"b\\c\nd\te\u0041""#),
+            Ok(JsonValue::String("a\"b\\c\nd\teA".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_json_empty_object() {
+        assert_eq!(parse_json("{}"), Ok(JsonValue::Object(HashMap::new())));
+        assert_eq!(parse_json(" { \n } "), parse_json("{}"));
+    }
+
+    #[test]
+    fn test_parse_json_nested() {
+        let depth = 64;
+        let input = format!("{}1{}", "[".repeat(depth), "]".repeat(depth));
+        let mut value = &parse_json(&input).unwrap();
+        for _ in 0..depth {
+            match value {
+                JsonValue::Array(items) if items.len() == 1 => value = &items[0],
+                other => panic!("expected single-item array, got {:?}", other),
+            }
+        }
+        assert_eq!(value, &JsonValue::Number(1.0));
+
+        let doc = parse_json(r#"{"user": {"name": "Alice", "tags": ["a", null, 2]}}"#).unwrap();
+        assert_eq!(doc.get_path("user.name").and_then(|v| v.as_string()), Some("Alice"));
+    }
+
+    #[test]
+    fn test_parse_json_depth_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_json(&nested(MAX_JSON_DEPTH)).is_ok());
+        assert_eq!(
+            parse_json(&nested(MAX_JSON_DEPTH + 1)),
+            Err(JsonParseError::new(MAX_JSON_DEPTH, "nesting too deep"))
+        );
+
+        // Far deeper than the stack could take: still an Err, not an abort
+        let deep = "[".repeat(100_000);
+        assert_eq!(parse_json(&deep).unwrap_err().reason, "nesting too deep");
+        let objects = r#"{"a":"#.repeat(100_000);
+        assert_eq!(parse_json(&objects).unwrap_err().reason, "nesting too deep");
+    }
+
+    #[test]
+    fn test_parse_json_malformed() {
+        let err = |input: &str| parse_json(input).unwrap_err();
+
+        assert_eq!(err("[1, 2,]"), JsonParseError::new(6, "trailing comma"));
+        assert_eq!(err(r#"{"a": 1,}"#).reason, "trailing comma");
+        assert_eq!(err(r#""abc"#).reason, "unterminated string");
+        assert_eq!(err(r#"{"a": "b}"#).reason, "unterminated string");
+        assert_eq!(err("").reason, "unexpected end of input");
+        assert_eq!(err("tru").reason, "unexpected token");
+        assert_eq!(err("[1 2]").reason, "expected ',' or ']'");
+        assert_eq!(err("{1: 2}").reason, "expected string");
+        assert_eq!(err("-").reason, "invalid number");
+        assert_eq!(err("1 2"), JsonParseError::new(2, "trailing characters"));
+        assert_eq!(err(r#""\x""#).reason, "invalid escape");
+    }
+
+    #[test]
+    fn test_parse_json_round_trip() {
+        let input = r#"{"name": "Alice", "scores": [1, 2.5, -3], "active": true, "extra": null}"#;
+        let value = parse_json(input).unwrap();
+        assert_eq!(parse_json(&value.to_string()), Ok(value));
+    }
+
     #[test]
     fn test_database_error() {
         let err = DatabaseError::NotFound {
@@ -36,6 +36,28 @@ impl Rectangle {
         // TODO: self กว้างและยาวพอที่จะใส่ other
         false
     }
+
+    /// คำนวณเส้นรอบรูป 2 * (width + height)
+    /// ใช้ u32 ธรรมดา ไม่ได้กัน overflow เพราะขนาดที่ใช้จริงไม่ใหญ่ขนาดนั้น
+    pub fn perimeter(&self) -> u32 {
+        2 * (self.width + self.height)
+    }
+
+    /// สร้างสี่เหลี่ยมใหม่ที่ขยายทั้งสองด้านด้วย factor (ไม่แก้ตัวเดิม)
+    pub fn scale(&self, factor: u32) -> Rectangle {
+        Rectangle {
+            width: self.width * factor,
+            height: self.height * factor,
+        }
+    }
+
+    /// สร้างสี่เหลี่ยมใหม่ที่สลับ width กับ height
+    pub fn rotate(&self) -> Rectangle {
+        Rectangle {
+            width: self.height,
+            height: self.width,
+        }
+    }
 }
 
 // ============================================
@@ -56,6 +78,73 @@ impl Direction {
         // TODO: North -> South, East -> West, etc.
         Direction::North
     }
+
+    /// ชื่อทิศเป็นตัวพิมพ์เล็ก ใช้คู่กับ `parse` ได้
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::East => "east",
+            Direction::West => "west",
+        }
+    }
+
+    /// หมุนทวนเข็มนาฬิกา 90 องศา (North -> West)
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    /// หมุนตามเข็มนาฬิกา 90 องศา (North -> East)
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// เวกเตอร์การเคลื่อนที่ 1 ช่อง (x, y) โดย North คือ (0, 1)
+    pub fn to_delta(&self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::South => (0, -1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+/// error เมื่อแปลงข้อความเป็น Direction ไม่ได้ เก็บข้อความเดิมไว้
+#[derive(Debug, PartialEq)]
+pub struct ParseDirectionError(pub String);
+
+impl std::fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown direction: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// รับ "north" / "n" ฯลฯ โดยไม่สนตัวพิมพ์เล็กใหญ่
+impl std::str::FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "north" | "n" => Ok(Direction::North),
+            "south" | "s" => Ok(Direction::South),
+            "east" | "e" => Ok(Direction::East),
+            "west" | "w" => Ok(Direction::West),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -76,6 +165,42 @@ impl Message {
         // ChangeColor -> "Changing color to r, g, b"
         String::new()
     }
+
+    /// แปลงเป็นข้อความสั้นๆ เช่น "MOVE 10 20", "WRITE Hello", "COLOR 255 0 0", "QUIT"
+    pub fn serialize(&self) -> String {
+        match self {
+            Message::Quit => String::from("QUIT"),
+            Message::Move { x, y } => format!("MOVE {} {}", x, y),
+            Message::Write(text) => format!("WRITE {}", text),
+            Message::ChangeColor(r, g, b) => format!("COLOR {} {} {}", r, g, b),
+        }
+    }
+
+    /// แปลงข้อความจาก `serialize` กลับเป็น Message
+    pub fn deserialize(s: &str) -> Result<Message, String> {
+        let (tag, rest) = s.split_once(' ').unwrap_or((s, ""));
+        match tag {
+            "QUIT" if rest.is_empty() => Ok(Message::Quit),
+            // ข้อความของ WRITE อาจมีช่องว่าง จึงเอาทั้งหมดที่เหลือ
+            "WRITE" => Ok(Message::Write(rest.to_string())),
+            "MOVE" => match parse_fields::<i32>(rest)?.as_slice() {
+                [x, y] => Ok(Message::Move { x: *x, y: *y }),
+                _ => Err(format!("MOVE needs 2 numbers: '{}'", s)),
+            },
+            "COLOR" => match parse_fields::<u8>(rest)?.as_slice() {
+                [r, g, b] => Ok(Message::ChangeColor(*r, *g, *b)),
+                _ => Err(format!("COLOR needs 3 numbers: '{}'", s)),
+            },
+            _ => Err(format!("unknown message: '{}'", s)),
+        }
+    }
+}
+
+/// แยกตัวเลขที่คั่นด้วยช่องว่าง
+fn parse_fields<T: std::str::FromStr>(s: &str) -> Result<Vec<T>, String> {
+    s.split_whitespace()
+        .map(|field| field.parse().map_err(|_| format!("invalid number: '{}'", field)))
+        .collect()
 }
 
 // ============================================
@@ -100,6 +225,12 @@ pub fn add_options(a: Option<i32>, b: Option<i32>) -> Option<i32> {
     None
 }
 
+/// รวมผลแบบกัน overflow: คืน None ทันทีที่ผลรวมเกินช่วงของ i32
+/// `try_fold` หยุดวนเมื่อ closure คืน None เหมือนกับ `?`
+pub fn checked_sum(numbers: &[i32]) -> Option<i32> {
+    numbers.iter().try_fold(0i32, |total, &n| total.checked_add(n))
+}
+
 // ============================================
 // EXERCISE 4: Result
 // ============================================
@@ -156,6 +287,23 @@ pub fn count_quarters(coins: &[Coin]) -> (usize, u32) {
     (0, 0)
 }
 
+/// รวมมูลค่าเหรียญทั้งหมดเป็นเซ็นต์
+/// ต้องแปลง u8 เป็น u32 ก่อนบวก ไม่งั้นแค่ 11 Quarter (275) ก็ overflow แล้ว
+pub fn total_value(coins: &[Coin]) -> u32 {
+    coins.iter().map(|coin| coin_value(coin) as u32).sum()
+}
+
+/// คืนเหรียญที่มีมูลค่าสูงสุด (ถ้าเท่ากันคืนตัวแรก) หรือ None ถ้าไม่มีเหรียญ
+pub fn most_valuable(coins: &[Coin]) -> Option<&Coin> {
+    coins.iter().reduce(|best, coin| {
+        if coin_value(coin) > coin_value(best) {
+            coin
+        } else {
+            best
+        }
+    })
+}
+
 // ============================================
 // TESTS
 // ============================================
@@ -183,6 +331,24 @@ mod tests {
         assert!(!rect1.can_hold(&rect3));
     }
 
+    #[test]
+    fn test_rectangle_perimeter_scale_rotate() {
+        let rect = Rectangle { width: 3, height: 5 };
+        assert_eq!(rect.perimeter(), 16);
+        assert_eq!(rect.scale(2), Rectangle { width: 6, height: 10 });
+        assert_eq!(rect.rotate(), Rectangle { width: 5, height: 3 });
+        assert_eq!(rect.rotate().rotate(), rect);
+
+        let unit = Rectangle { width: 1, height: 1 };
+        assert_eq!(unit.perimeter(), 4);
+        assert_eq!(unit.rotate(), unit);
+        assert_eq!(unit.scale(0), Rectangle { width: 0, height: 0 });
+
+        let empty = Rectangle { width: 0, height: 0 };
+        assert_eq!(empty.perimeter(), 0);
+        assert_eq!(empty.scale(7), empty);
+    }
+
     #[test]
     fn test_direction_opposite() {
         assert_eq!(Direction::North.opposite(), Direction::South);
@@ -191,6 +357,70 @@ mod tests {
         assert_eq!(Direction::West.opposite(), Direction::East);
     }
 
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+
+        for d in [Direction::North, Direction::South, Direction::East, Direction::West] {
+            assert_eq!(d.turn_left().turn_left().turn_left().turn_left(), d);
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_left().turn_left(), d.turn_right().turn_right());
+        }
+    }
+
+    #[test]
+    fn test_direction_half_turn() {
+        // เลี้ยวสองครั้งคือกลับหลังหัน (ไม่พึ่ง `opposite` ที่ยังเป็นแบบฝึกหัด)
+        let pairs = [
+            (Direction::North, Direction::South),
+            (Direction::South, Direction::North),
+            (Direction::East, Direction::West),
+            (Direction::West, Direction::East),
+        ];
+        for (d, back) in pairs {
+            assert_eq!(d.turn_left().turn_left(), back);
+            assert_eq!(d.turn_right().turn_right(), back);
+        }
+    }
+
+    #[test]
+    fn test_direction_to_delta() {
+        assert_eq!(Direction::North.to_delta(), (0, 1));
+        assert_eq!(Direction::South.to_delta(), (0, -1));
+        assert_eq!(Direction::East.to_delta(), (1, 0));
+        assert_eq!(Direction::West.to_delta(), (-1, 0));
+
+        // เดินหน้าแล้วเลี้ยวขวาครบ 4 ครั้งต้องกลับที่เดิม
+        let mut pos = (0, 0);
+        let mut facing = Direction::North;
+        for _ in 0..4 {
+            let (dx, dy) = facing.to_delta();
+            pos = (pos.0 + dx, pos.1 + dy);
+            facing = facing.turn_right();
+        }
+        assert_eq!(pos, (0, 0));
+        assert_eq!(facing, Direction::North);
+    }
+
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!("North".parse::<Direction>(), Ok(Direction::North));
+        assert_eq!("s".parse::<Direction>(), Ok(Direction::South));
+        assert_eq!(" EAST ".parse::<Direction>(), Ok(Direction::East));
+        assert_eq!(
+            "XYZ".parse::<Direction>(),
+            Err(ParseDirectionError(String::from("XYZ")))
+        );
+    }
+
+    #[test]
+    fn test_direction_as_str_round_trip() {
+        for dir in [Direction::North, Direction::South, Direction::East, Direction::West] {
+            assert_eq!(dir.as_str().parse::<Direction>(), Ok(dir));
+        }
+    }
+
     #[test]
     fn test_message_process() {
         assert_eq!(Message::Quit.process(), "Quitting...");
@@ -208,6 +438,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_message_serialize() {
+        assert_eq!(Message::Quit.serialize(), "QUIT");
+        assert_eq!(Message::Move { x: 10, y: -20 }.serialize(), "MOVE 10 -20");
+        assert_eq!(Message::Write(String::from("Hello")).serialize(), "WRITE Hello");
+        assert_eq!(Message::ChangeColor(255, 0, 0).serialize(), "COLOR 255 0 0");
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let messages = vec![
+            Message::Quit,
+            Message::Move { x: 10, y: -20 },
+            Message::Write(String::from("Hello, world!")),
+            Message::Write(String::new()),
+            Message::ChangeColor(255, 0, 128),
+        ];
+        for msg in messages {
+            assert_eq!(Message::deserialize(&msg.serialize()), Ok(msg));
+        }
+    }
+
+    #[test]
+    fn test_message_deserialize_errors() {
+        assert!(Message::deserialize("").is_err());
+        assert!(Message::deserialize("JUMP 1").is_err());
+        assert!(Message::deserialize("QUIT now").is_err());
+        assert!(Message::deserialize("MOVE 10").is_err());
+        assert!(Message::deserialize("MOVE ten 20").is_err());
+        assert!(Message::deserialize("COLOR 256 0 0").is_err());
+    }
+
     #[test]
     fn test_find_index() {
         let vec = vec![1, 2, 3, 4, 5];
@@ -230,6 +492,17 @@ mod tests {
         assert_eq!(add_options(None, Some(10)), None);
     }
 
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum(&[1, 2, 3, -4]), Some(2));
+        assert_eq!(checked_sum(&[]), Some(0));
+        assert_eq!(checked_sum(&[i32::MAX - 1, 1]), Some(i32::MAX));
+        assert_eq!(checked_sum(&[i32::MAX, 1]), None);
+        assert_eq!(checked_sum(&[i32::MIN, -1]), None);
+        // ล้นระหว่างทางก็ต้องได้ None แม้ผลรวมสุดท้ายจะอยู่ในช่วง
+        assert_eq!(checked_sum(&[i32::MAX, 1, -1]), None);
+    }
+
     #[test]
     fn test_parse_number() {
         assert_eq!(parse_number("42"), Ok(42));
@@ -270,4 +543,31 @@ mod tests {
         ];
         assert_eq!(count_quarters(&coins), (2, 36)); // 2 quarters = 50, + 1 + 10 = 61
     }
+
+    #[test]
+    fn test_total_value() {
+        let coins = vec![
+            Coin::Penny,
+            Coin::Dime,
+            Coin::Quarter(String::from("Alaska")),
+            Coin::Nickel,
+        ];
+        assert_eq!(total_value(&coins), 41);
+        assert_eq!(total_value(&[]), 0);
+
+        // 20 Quarter = 500 เกินขนาดของ u8
+        let many: Vec<Coin> = (0..20).map(|_| Coin::Quarter(String::from("Ohio"))).collect();
+        assert_eq!(total_value(&many), 500);
+    }
+
+    #[test]
+    fn test_most_valuable() {
+        let coins = vec![
+            Coin::Penny,
+            Coin::Quarter(String::from("Alaska")),
+            Coin::Dime,
+        ];
+        assert_eq!(most_valuable(&coins), Some(&Coin::Quarter(String::from("Alaska"))));
+        assert_eq!(most_valuable(&[]), None);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Advanced vector and HashMap patterns
 
-use std::collections::{HashMap, HashSet, BTreeMap, VecDeque};
+use std::collections::{HashMap, HashSet, BTreeMap, BTreeSet, VecDeque};
 use std::hash::{Hash, Hasher};
 
 /// LRU Cache using VecDeque + HashMap
@@ -49,6 +49,11 @@ impl<K: Eq + Hash + Clone, V> LRUCache<K, V> {
         }
         self.order.push_front(key);
     }
+
+    /// Entries from most- to least-recently used (does not touch recency)
+    pub fn iter_mru(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.order.iter().filter_map(|k| self.map.get_key_value(k))
+    }
 }
 
 /// Group-by operation (like SQL GROUP BY)
@@ -66,6 +71,26 @@ where
     groups
 }
 
+/// Group-by that keeps groups in first-seen key order
+/// Shows: auxiliary index map alongside an ordered Vec
+pub fn group_by_ordered<T, K, F>(items: Vec<T>, key_fn: F) -> Vec<(K, Vec<T>)>
+where
+    K: Eq + Hash + Clone,
+    F: Fn(&T) -> K,
+{
+    let mut index: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<(K, Vec<T>)> = Vec::new();
+    for item in items {
+        let key = key_fn(&item);
+        let slot = *index.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[slot].1.push(item);
+    }
+    groups
+}
+
 /// Multi-key index for fast lookups
 /// Shows: multiple HashMaps, maintaining consistency
 pub struct MultiIndex<T: Clone> {
@@ -140,6 +165,32 @@ pub fn find_duplicates<T: Eq + Hash + Clone>(items: &[T]) -> Vec<T> {
     duplicates.into_iter().collect()
 }
 
+/// Same as `find_duplicates`, but deterministic: ascending order
+/// Shows: BTreeSet keeps elements sorted, so no HashSet iteration order leaks out
+pub fn find_duplicates_sorted<T: Ord + Clone>(items: &[T]) -> Vec<T> {
+    let mut seen = BTreeSet::new();
+    let mut duplicates = BTreeSet::new();
+
+    for item in items {
+        if !seen.insert(item) {
+            duplicates.insert(item.clone());
+        }
+    }
+
+    duplicates.into_iter().collect()
+}
+
+/// Removing duplicates while keeping first-occurrence order
+/// Shows: HashSet::insert as a "seen before?" check, the inverse of `find_duplicates`
+pub fn dedup_preserve_order<T: Eq + Hash + Clone>(items: &[T]) -> Vec<T> {
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
 /// Top-K elements using BTreeMap
 /// Shows: sorted collections, range queries
 pub fn top_k_by_frequency(words: Vec<String>, k: usize) -> Vec<(String, usize)> {
@@ -164,6 +215,40 @@ pub fn top_k_by_frequency(words: Vec<String>, k: usize) -> Vec<(String, usize)>
     result
 }
 
+/// Time series keyed by timestamp
+/// Shows: BTreeMap::range for half-open and "latest before" queries
+#[derive(Debug, Default)]
+pub struct TimeSeries {
+    points: BTreeMap<u64, f64>,
+}
+
+impl TimeSeries {
+    pub fn new() -> Self {
+        TimeSeries {
+            points: BTreeMap::new(),
+        }
+    }
+
+    /// Overwrites any value already stored at `t`
+    pub fn insert(&mut self, t: u64, v: f64) {
+        self.points.insert(t, v);
+    }
+
+    /// Points with timestamps in `[from, to)`, oldest first
+    pub fn range(&self, from: u64, to: u64) -> Vec<(u64, f64)> {
+        // BTreeMap::range panics when start > end
+        if from >= to {
+            return Vec::new();
+        }
+        self.points.range(from..to).map(|(&t, &v)| (t, v)).collect()
+    }
+
+    /// Most recent point strictly before `t`
+    pub fn latest_before(&self, t: u64) -> Option<(u64, f64)> {
+        self.points.range(..t).next_back().map(|(&t, &v)| (t, v))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +265,18 @@ mod tests {
         assert_eq!(cache.get(&"c"), Some(&3));
     }
 
+    #[test]
+    fn test_lru_iter_mru() {
+        let mut cache = LRUCache::new(3);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        cache.get(&"a");
+
+        let order: Vec<_> = cache.iter_mru().collect();
+        assert_eq!(order, vec![(&"a", &1), (&"c", &3), (&"b", &2)]);
+    }
+
     #[test]
     fn test_group_by() {
         let items = vec![1, 2, 3, 4, 5, 6];
@@ -189,6 +286,17 @@ mod tests {
         assert_eq!(grouped.get(&1), Some(&vec![1, 3, 5]));
     }
 
+    #[test]
+    fn test_group_by_ordered() {
+        let words = vec!["apple", "banana", "avocado"];
+        let grouped = group_by_ordered(words, |w| w.chars().next().unwrap());
+
+        assert_eq!(
+            grouped,
+            vec![('a', vec!["apple", "avocado"]), ('b', vec!["banana"])]
+        );
+    }
+
     #[test]
     fn test_multi_index() {
         let mut index = MultiIndex::new();
@@ -225,6 +333,20 @@ mod tests {
         assert!(!dups.contains(&1));
     }
 
+    #[test]
+    fn test_find_duplicates_sorted() {
+        assert_eq!(find_duplicates_sorted(&[1, 2, 3, 2, 4, 3, 5]), vec![2, 3]);
+        assert_eq!(find_duplicates_sorted(&[9, 9, 1, 9, 1]), vec![1, 9]);
+        assert!(find_duplicates_sorted(&["a", "b"]).is_empty());
+    }
+
+    #[test]
+    fn test_dedup_preserve_order() {
+        assert_eq!(dedup_preserve_order(&[3, 1, 3, 2, 1]), vec![3, 1, 2]);
+        assert_eq!(dedup_preserve_order(&["b", "a", "b"]), vec!["b", "a"]);
+        assert!(dedup_preserve_order::<i32>(&[]).is_empty());
+    }
+
     #[test]
     fn test_top_k() {
         let words = vec![
@@ -240,4 +362,30 @@ mod tests {
         assert_eq!(top[0], ("rust".to_string(), 3));
         assert_eq!(top[1], ("java".to_string(), 2));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_time_series_range() {
+        let mut series = TimeSeries::new();
+        for (t, v) in [(30, 3.0), (10, 1.0), (20, 2.0), (40, 4.0)] {
+            series.insert(t, v);
+        }
+
+        assert_eq!(series.range(15, 40), vec![(20, 2.0), (30, 3.0)]);
+        assert_eq!(series.range(10, 11), vec![(10, 1.0)]);
+        assert_eq!(series.range(0, 100).len(), 4);
+        assert!(series.range(40, 40).is_empty());
+        assert!(series.range(50, 10).is_empty());
+    }
+
+    #[test]
+    fn test_time_series_latest_before() {
+        let mut series = TimeSeries::new();
+        series.insert(10, 1.0);
+        series.insert(20, 2.0);
+        series.insert(20, 2.5);
+
+        assert_eq!(series.latest_before(25), Some((20, 2.5)));
+        assert_eq!(series.latest_before(20), Some((10, 1.0)));
+        assert_eq!(series.latest_before(10), None);
+    }
+}
@@ -40,6 +40,43 @@ pub fn interleave(a: &[i32], b: &[i32]) -> Vec<i32> {
     result
 }
 
+/// นับคู่ (i, j) ที่ i < j แต่ nums[i] > nums[j]
+/// ใช้ merge sort จึงเป็น O(n log n) แทนการเทียบทุกคู่ O(n²)
+pub fn count_inversions(nums: &[i32]) -> u64 {
+    let mut sorted = nums.to_vec();
+    sort_and_count(&mut sorted)
+}
+
+/// เรียง slice แล้วคืนจำนวน inversion ที่เจอระหว่างเรียง
+fn sort_and_count(nums: &mut [i32]) -> u64 {
+    if nums.len() <= 1 {
+        return 0;
+    }
+    
+    let mid = nums.len() / 2;
+    let mut count = sort_and_count(&mut nums[..mid]) + sort_and_count(&mut nums[mid..]);
+    
+    let mut merged = Vec::with_capacity(nums.len());
+    let (left, right) = nums.split_at(mid);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] <= right[j] {
+            merged.push(left[i]);
+            i += 1;
+        } else {
+            // right[j] น้อยกว่าทุกตัวที่เหลือใน left
+            count += (left.len() - i) as u64;
+            merged.push(right[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    
+    nums.copy_from_slice(&merged);
+    count
+}
+
 // TESTS
 #[cfg(test)]
 mod tests {
@@ -68,4 +105,13 @@ mod tests {
             vec![1, 10, 2, 20, 30]
         );
     }
+
+    #[test]
+    fn test_count_inversions() {
+        assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+        assert_eq!(count_inversions(&[1, 2, 3, 4, 5]), 0);
+        assert_eq!(count_inversions(&[5, 4, 3, 2, 1]), 10);
+        assert_eq!(count_inversions(&[2, 2, 1]), 2);
+        assert_eq!(count_inversions(&[]), 0);
+    }
 }
@@ -129,26 +129,56 @@ pub fn validate_user(name: &str, age: i32, email: &str) -> Result<()> {
 
 /// Retry with exponential backoff
 /// Shows: error handling in loops, sleep
-pub fn with_retry<F, T>(mut f: F, max_attempts: u32) -> std::result::Result<T, AppError>
+pub fn with_retry<F, T>(f: F, max_attempts: u32) -> std::result::Result<T, AppError>
 where
     F: FnMut() -> std::result::Result<T, AppError>,
 {
-    let mut last_error = None;
+    with_retry_when(f, max_attempts, |_| true)
+}
+
+/// Shared retry loop: retries while `should_retry` accepts the error
+/// `max_attempts` is clamped to at least 1, so `f` always runs once
+/// and the last error is returned instead of panicking on 0
+pub fn with_retry_when<F, T, P>(
+    mut f: F,
+    max_attempts: u32,
+    should_retry: P,
+) -> std::result::Result<T, AppError>
+where
+    F: FnMut() -> std::result::Result<T, AppError>,
+    P: Fn(&AppError) -> bool,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut attempt = 1;
     
-    for attempt in 1..=max_attempts {
+    loop {
         match f() {
             Ok(result) => return Ok(result),
-            Err(e) => {
-                last_error = Some(e);
-                if attempt < max_attempts {
-                    let delay = std::time::Duration::from_millis(100 * 2_u64.pow(attempt - 1));
-                    std::thread::sleep(delay);
-                }
+            Err(e) if attempt >= max_attempts || !should_retry(&e) => return Err(e),
+            Err(_) => {
+                let delay = std::time::Duration::from_millis(100 * 2_u64.pow(attempt - 1));
+                std::thread::sleep(delay);
+                attempt += 1;
             }
         }
     }
-    
-    Err(last_error.unwrap())
+}
+
+/// Only I/O failures are worth retrying; bad input or config fails the same way every time
+pub fn is_transient(e: &AppError) -> bool {
+    match e {
+        AppError::Io { .. } => true,
+        AppError::Parse { .. } | AppError::Config { .. } | AppError::Validation(_) => false,
+    }
+}
+
+/// Retry only transient errors, give up immediately on permanent ones
+/// Shows: match guards on an error enum, retry policy as a separate function
+pub fn with_retry_classified<F, T>(f: F, max_attempts: u32) -> std::result::Result<T, AppError>
+where
+    F: FnMut() -> std::result::Result<T, AppError>,
+{
+    with_retry_when(f, max_attempts, is_transient)
 }
 
 /// Error conversion with From trait
@@ -171,6 +201,20 @@ impl From<std::num::ParseIntError> for AppError {
     }
 }
 
+/// Collecting Results
+/// Shows: collect::<Result<Vec<_>, _>>() stops at the first Err
+pub fn parse_all(inputs: &[&str]) -> Result<Vec<i32>> {
+    inputs
+        .iter()
+        .map(|s| {
+            s.parse::<i32>().map_err(|e| AppError::Parse {
+                input: s.to_string(),
+                reason: e.to_string(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+}
+
 /// Using the ? operator with custom errors
 pub fn read_config_file(path: &str) -> Result<String> {
     std::fs::read_to_string(path)
@@ -198,6 +242,74 @@ pub fn load_configuration(path: &str) -> Result<u16> {
     Ok(port)
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub workers: usize,
+}
+
+/// Batch validation over config parsing
+/// Shows: collecting every missing/malformed key instead of stopping at the first
+pub fn load_full_config(lines: &[&str]) -> Result<ServerConfig> {
+    let mut errors = Vec::new();
+
+    let host = match config_value(lines, "host") {
+        Some(host) if !host.is_empty() => Some(host.to_string()),
+        _ => {
+            errors.push(field_error("host", "missing".to_string()));
+            None
+        }
+    };
+
+    let port = match config_value(lines, "port") {
+        None => {
+            errors.push(field_error("port", "missing".to_string()));
+            None
+        }
+        Some(raw) => match raw.parse::<u16>() {
+            Ok(port) if port != 0 => Some(port),
+            _ => {
+                errors.push(field_error("port", format!("'{}' is not a valid port number", raw)));
+                None
+            }
+        },
+    };
+
+    let workers = match config_value(lines, "workers") {
+        None => {
+            errors.push(field_error("workers", "missing".to_string()));
+            None
+        }
+        Some(raw) => match raw.parse::<usize>() {
+            Ok(workers) if workers > 0 => Some(workers),
+            _ => {
+                errors.push(field_error("workers", format!("'{}' is not a positive count", raw)));
+                None
+            }
+        },
+    };
+
+    match (host, port, workers) {
+        (Some(host), Some(port), Some(workers)) => Ok(ServerConfig { host, port, workers }),
+        _ => Err(AppError::Validation(errors)),
+    }
+}
+
+fn config_value<'a>(lines: &[&'a str], key: &str) -> Option<&'a str> {
+    lines
+        .iter()
+        .find_map(|line| line.trim().strip_prefix(key)?.strip_prefix('='))
+        .map(str::trim)
+}
+
+fn field_error(field: &str, message: String) -> ValidationError {
+    ValidationError {
+        field: field.to_string(),
+        message,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +345,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_full_config() {
+        let config = load_full_config(&["host=localhost", "port=8080", "workers=4"]).unwrap();
+        assert_eq!(
+            config,
+            ServerConfig {
+                host: "localhost".to_string(),
+                port: 8080,
+                workers: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_full_config_reports_all_errors() {
+        let err = load_full_config(&["host=localhost", "port=http", "# workers unset"]).unwrap_err();
+        match err {
+            AppError::Validation(errors) => {
+                let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields.len(), 2);
+                assert!(fields.contains(&"port"));
+                assert!(fields.contains(&"workers"));
+            }
+            _ => panic!("Expected validation error"),
+        }
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_all(&["1", "2", "3"]).unwrap(), vec![1, 2, 3]);
+
+        let err = parse_all(&["1", "x", "y"]).unwrap_err();
+        match &err {
+            AppError::Parse { input, .. } => assert_eq!(input, "x"),
+            _ => panic!("Expected parse error"),
+        }
+        assert!(err.to_string().contains("'x'"));
+    }
+
     #[test]
     fn test_retry_success() {
         let mut attempts = 0;
@@ -252,6 +403,69 @@ mod tests {
         assert_eq!(attempts, 3);
     }
 
+    #[test]
+    fn test_is_transient() {
+        let io_err: AppError = io::Error::new(io::ErrorKind::TimedOut, "slow").into();
+        assert!(is_transient(&io_err));
+        assert!(!is_transient(&AppError::Validation(vec![])));
+        assert!(!is_transient(&AppError::Config {
+            key: "port".to_string(),
+            message: "missing".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_retry_classified_transient_then_success() {
+        let mut attempts = 0;
+        let result = with_retry_classified(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err(io::Error::new(io::ErrorKind::Interrupted, "flaky").into())
+            } else {
+                Ok("done")
+            }
+        }, 5);
+        
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn test_retry_classified_permanent_fails_fast() {
+        let mut attempts = 0;
+        let result: Result<()> = with_retry_classified(|| {
+            attempts += 1;
+            Err(AppError::Validation(vec![]))
+        }, 5);
+        
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_zero_attempts_runs_once() {
+        let mut attempts = 0;
+        let result: Result<()> = with_retry(|| {
+            attempts += 1;
+            Err(io::Error::new(io::ErrorKind::TimedOut, "slow").into())
+        }, 0);
+        
+        assert!(matches!(result, Err(AppError::Io { .. })));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_max_attempts() {
+        let mut attempts = 0;
+        let result: Result<()> = with_retry_classified(|| {
+            attempts += 1;
+            Err(io::Error::new(io::ErrorKind::Interrupted, "flaky").into())
+        }, 2);
+        
+        assert!(result.is_err());
+        assert_eq!(attempts, 2);
+    }
+
     #[test]
     fn test_from_conversions() {
         let io_err = io::Error::new(io::ErrorKind::Other, "test");
@@ -15,6 +15,7 @@ pub fn read_file_contents(path: &str) -> Result<String, io::Error> {
 pub struct Config {
     pub query: String,
     pub filename: String,
+    pub case_sensitive: bool,
 }
 
 impl Config {
@@ -27,7 +28,35 @@ impl Config {
         let query = args[1].clone();
         let filename = args[2].clone();
         
-        Ok(Config { query, filename })
+        Ok(Config {
+            query,
+            filename,
+            case_sensitive: true,
+        })
+    }
+    
+    /// อ่านตัวเลือกเพิ่มเติมจาก environment variables
+    /// CASE_INSENSITIVE=<ค่าใดก็ได้ที่ไม่ว่าง> → ค้นหาแบบไม่สนตัวพิมพ์
+    pub fn with_env(mut self) -> Config {
+        self.apply_case_env(std::env::var("CASE_INSENSITIVE").ok());
+        self
+    }
+    
+    /// แยกออกมาจาก `with_env` เพื่อให้เทสต์ส่งค่าเข้ามาเองได้
+    /// โดยไม่ต้องแก้ environment ของทั้ง process
+    pub fn apply_case_env(&mut self, value: Option<String>) {
+        if value.is_some_and(|v| !v.is_empty()) {
+            self.case_sensitive = false;
+        }
+    }
+    
+    /// ค้นหา query ใน contents ตามโหมด case_sensitive ของ config
+    pub fn search_in<'a>(&self, contents: &'a str) -> Vec<&'a str> {
+        if self.case_sensitive {
+            search(&self.query, contents)
+        } else {
+            search_case_insensitive(&self.query, contents)
+        }
     }
 }
 
@@ -39,6 +68,42 @@ pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
         .collect()
 }
 
+/// เหมือน `search` แต่ไม่สนตัวพิมพ์เล็ก-ใหญ่
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// หา lines ที่มีทุก query (`all = true`) หรือมี query ใดก็ได้ (`all = false`)
+/// ถ้า queries ว่าง: โหมด all คืนทุกบรรทัด, โหมด any ไม่คืนอะไรเลย
+pub fn search_multi<'a>(queries: &[&str], contents: &'a str, all: bool) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| {
+            if all {
+                queries.iter().all(|q| line.contains(q))
+            } else {
+                queries.iter().any(|q| line.contains(q))
+            }
+        })
+        .collect()
+}
+
+/// นับจำนวนครั้งที่ query ปรากฏทั้งหมด (บรรทัดเดียวอาจนับได้หลายครั้ง)
+/// นับแบบไม่ทับซ้อนกัน เช่น "aa" ใน "aaa" นับได้ 1 และ query ว่างคืน 0
+pub fn count_matches(query: &str, contents: &str) -> usize {
+    if query.is_empty() {
+        return 0;
+    }
+    contents
+        .lines()
+        .map(|line| line.matches(query).count())
+        .sum()
+}
+
 // TESTS
 #[cfg(test)]
 mod tests {
@@ -54,6 +119,44 @@ mod tests {
         let config = Config::new(&args).unwrap();
         assert_eq!(config.query, "query");
         assert_eq!(config.filename, "file.txt");
+        assert!(config.case_sensitive);
+    }
+
+    #[test]
+    fn test_apply_case_env() {
+        let args = vec![
+            String::from("program"),
+            String::from("query"),
+            String::from("file.txt"),
+        ];
+
+        let mut config = Config::new(&args).unwrap();
+        config.apply_case_env(None);
+        assert!(config.case_sensitive);
+
+        config.apply_case_env(Some(String::new()));
+        assert!(config.case_sensitive);
+
+        config.apply_case_env(Some(String::from("1")));
+        assert!(!config.case_sensitive);
+    }
+
+    #[test]
+    fn test_search_in_respects_case_sensitive() {
+        let contents = "Rust:\nsafe, fast.\nTrust me.";
+        let mut config = Config {
+            query: String::from("rUsT"),
+            filename: String::from("poem.txt"),
+            case_sensitive: true,
+        };
+        assert!(config.search_in(contents).is_empty());
+
+        config.apply_case_env(Some(String::from("1")));
+        assert_eq!(config.search_in(contents), vec!["Rust:", "Trust me."]);
+
+        config.query = String::from("Rust");
+        config.case_sensitive = true;
+        assert_eq!(config.search_in(contents), vec!["Rust:"]);
     }
 
     #[test]
@@ -79,4 +182,39 @@ Trust me.";
             vec!["safe, fast, productive."]
         );
     }
+
+    #[test]
+    fn test_search_multi() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me, it's fast.";
+
+        assert_eq!(
+            search_multi(&["ust", "fast"], contents, true),
+            vec!["Trust me, it's fast."]
+        );
+        assert_eq!(
+            search_multi(&["Pick", "safe"], contents, false),
+            vec!["safe, fast, productive.", "Pick three."]
+        );
+        assert!(search_multi(&["Pick", "safe"], contents, true).is_empty());
+        assert!(search_multi(&[], contents, false).is_empty());
+    }
+
+    #[test]
+    fn test_count_matches() {
+        let contents = "\
+Rust is fast, fast to write.
+Pick three.
+Trust me.";
+
+        assert_eq!(count_matches("fast", contents), 2);
+        assert_eq!(search("fast", contents).len(), 1);
+        assert_eq!(count_matches("ust", contents), 2);
+        assert_eq!(count_matches("aa", "aaa"), 1);
+        assert_eq!(count_matches("slow", contents), 0);
+        assert_eq!(count_matches("", contents), 0);
+    }
 }
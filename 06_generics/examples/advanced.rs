@@ -3,7 +3,9 @@
 //! Type-level programming and constraints
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::Hash;
 
 /// Generic newtype pattern
 /// Shows: tuple struct, Deref, From/Into
@@ -75,6 +77,36 @@ impl<T> Container<T, Filled> {
     }
 }
 
+/// Phantom types for units instead of states
+/// Shows: zero-sized unit markers, From between differently-tagged values
+pub struct Celsius;
+pub struct Fahrenheit;
+
+#[derive(Debug)]
+pub struct Temperature<Unit>(f64, PhantomData<Unit>);
+
+impl<Unit> Temperature<Unit> {
+    pub fn new(value: f64) -> Self {
+        Temperature(value, PhantomData)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl From<Temperature<Celsius>> for Temperature<Fahrenheit> {
+    fn from(c: Temperature<Celsius>) -> Self {
+        Temperature::new(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Temperature<Fahrenheit>> for Temperature<Celsius> {
+    fn from(f: Temperature<Fahrenheit>) -> Self {
+        Temperature::new((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
 /// Generic trait bounds with multiple constraints
 /// Shows: where clauses, trait composition
 pub fn process_items<T, I>(items: I) -> Vec<T>
@@ -140,6 +172,215 @@ impl<T: Default + Copy, const N: usize> FixedVector<T, N> {
     }
 }
 
+/// Fixed-capacity FIFO queue that overwrites its oldest entry when full
+/// Shows: const generics with non-Copy items, wraparound indexing
+pub struct RingBuffer<T, const N: usize> {
+    slots: [Option<T>; N],
+    head: usize, // index of the oldest item
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new() -> Self {
+        RingBuffer {
+            slots: std::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the evicted oldest item when the buffer was already full
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            return Some(item);
+        }
+        let tail = (self.head + self.len) % N;
+        let evicted = self.slots[tail].replace(item);
+        if self.len == N {
+            self.head = (self.head + 1) % N;
+        } else {
+            self.len += 1;
+        }
+        evicted
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        item
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Growable LIFO stack backed by Vec
+/// Shows: generic container, FromIterator, borrowing iterators
+#[derive(Debug)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate from top to bottom
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().rev()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Sum type holding one of two values
+/// Shows: multiple type parameters, mapping one side independently
+#[derive(Debug, Clone, PartialEq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+
+    pub fn map_left<T, F: FnOnce(L) -> T>(self, f: F) -> Either<T, R> {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(r),
+        }
+    }
+
+    pub fn map_right<T, F: FnOnce(R) -> T>(self, f: F) -> Either<L, T> {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => Either::Right(f(r)),
+        }
+    }
+
+    /// Collapse both sides into a single type
+    pub fn either<T>(self, f: impl FnOnce(L) -> T, g: impl FnOnce(R) -> T) -> T {
+        match self {
+            Either::Left(l) => f(l),
+            Either::Right(r) => g(r),
+        }
+    }
+}
+
+/// Cache keyed by argument, generic over both key and result
+/// Shows: two type parameters with different bounds, FnOnce for lazy values
+pub struct Memo<A, R> {
+    cache: HashMap<A, R>,
+}
+
+impl<A: Eq + Hash + Clone, R: Clone> Memo<A, R> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, key: &A) -> Option<&R> {
+        self.cache.get(key)
+    }
+
+    /// Return the cached result for `key`, running `f` only on a miss
+    pub fn get_or_compute<F: FnOnce() -> R>(&mut self, key: A, f: F) -> R {
+        if let Some(result) = self.cache.get(&key) {
+            return result.clone();
+        }
+        let result = f();
+        self.cache.insert(key, result.clone());
+        result
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<A: Eq + Hash + Clone, R: Clone> Default for Memo<A, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Min-Max heap with custom comparators
 /// Shows: generic comparison, Fn traits
 pub fn find_extremes<T, F>(items: &[T], compare: F) -> Option<(&T, &T)>
@@ -165,6 +406,30 @@ where
     Some((min, max))
 }
 
+/// In-place bubble sort; only needs comparison, never copies elements
+/// Shows: generic algorithm over PartialOrd, slice::swap, early exit
+pub fn bubble_sort<T: PartialOrd>(slice: &mut [T]) {
+    let mut unsorted = slice.len();
+    while unsorted > 1 {
+        let mut swapped = false;
+        for i in 1..unsorted {
+            if slice[i - 1] > slice[i] {
+                slice.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        // The largest remaining item has bubbled to the end
+        unsorted -= 1;
+    }
+}
+
+pub fn is_sorted<T: PartialOrd>(slice: &[T]) -> bool {
+    slice.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
 /// Default trait for generic initialization
 /// Shows: Default, generic constraints
 #[derive(Debug)]
@@ -210,6 +475,19 @@ mod tests {
         // empty is now Empty type again
     }
 
+    #[test]
+    fn test_temperature_conversions() {
+        let boiling = Temperature::<Celsius>::new(100.0);
+        let f: Temperature<Fahrenheit> = boiling.into();
+        assert!((f.value() - 212.0).abs() < 1e-9);
+
+        let c: Temperature<Celsius> = f.into();
+        assert!((c.value() - 100.0).abs() < 1e-9);
+
+        let freezing: Temperature<Celsius> = Temperature::<Fahrenheit>::new(32.0).into();
+        assert!(freezing.value().abs() < 1e-9);
+    }
+
     #[test]
     fn test_fixed_vector() {
         let mut vec: FixedVector<i32, 3> = FixedVector::new();
@@ -221,6 +499,131 @@ mod tests {
         assert_eq!(vec.get(1), Some(&2));
     }
 
+    #[test]
+    fn test_ring_buffer_overwrites_oldest() {
+        let mut ring: RingBuffer<String, 3> = RingBuffer::new();
+        for word in ["a", "b", "c"] {
+            assert_eq!(ring.push(word.to_string()), None);
+        }
+        assert!(ring.is_full());
+
+        assert_eq!(ring.push("d".to_string()), Some("a".to_string()));
+        assert_eq!(ring.len(), 3);
+
+        assert_eq!(ring.pop().as_deref(), Some("b"));
+        assert_eq!(ring.pop().as_deref(), Some("c"));
+        assert_eq!(ring.pop().as_deref(), Some("d"));
+        assert_eq!(ring.pop(), None);
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_wraparound() {
+        let mut ring: RingBuffer<i32, 2> = RingBuffer::new();
+        for i in 0..5 {
+            ring.push(i);
+            assert_eq!(ring.pop(), Some(i));
+        }
+        ring.push(10);
+        ring.push(11);
+        ring.push(12);
+        assert_eq!(ring.pop(), Some(11));
+        assert_eq!(ring.pop(), Some(12));
+
+        let mut empty: RingBuffer<i32, 0> = RingBuffer::new();
+        assert_eq!(empty.push(1), Some(1));
+        assert_eq!(empty.pop(), None);
+    }
+
+    #[test]
+    fn test_stack_lifo() {
+        let mut stack: Stack<i32> = (1..=3).collect();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_stack_iter_top_first() {
+        let stack: Stack<&str> = vec!["bottom", "middle", "top"].into_iter().collect();
+        let items: Vec<_> = stack.iter().copied().collect();
+        assert_eq!(items, vec!["top", "middle", "bottom"]);
+    }
+
+    #[test]
+    fn test_either_map() {
+        let left: Either<i32, String> = Either::Left(2);
+        let right: Either<i32, String> = Either::Right("hi".to_string());
+
+        assert_eq!(left.clone().map_left(|n| n * 10), Either::Left(20));
+        assert_eq!(left.clone().map_right(|s| s.len()), Either::Left(2));
+        assert_eq!(right.clone().map_right(|s| s.len()), Either::Right(2));
+        assert_eq!(right.clone().map_left(|n| n * 10), Either::Right("hi".to_string()));
+
+        assert!(left.is_left());
+        assert!(right.is_right());
+        assert_eq!(left.left(), Some(2));
+        assert_eq!(right.left(), None);
+    }
+
+    #[test]
+    fn test_either_collapse() {
+        let values: Vec<Either<i32, &str>> = vec![Either::Left(7), Either::Right("abc")];
+        let rendered: Vec<String> = values
+            .into_iter()
+            .map(|e| e.either(|n| n.to_string(), |s| s.to_uppercase()))
+            .collect();
+        assert_eq!(rendered, vec!["7", "ABC"]);
+    }
+
+    // Recursion can't happen inside `get_or_compute` (it holds `&mut memo`),
+    // so check the cache first and store the result afterwards
+    fn fib(n: u64, memo: &mut Memo<u64, u64>, calls: &mut u32) -> u64 {
+        *calls += 1;
+        if n < 2 {
+            return n;
+        }
+        if let Some(cached) = memo.get(&n) {
+            return *cached;
+        }
+        let value = fib(n - 1, memo, calls) + fib(n - 2, memo, calls);
+        memo.get_or_compute(n, || value)
+    }
+
+    #[test]
+    fn test_memo_fibonacci() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        assert_eq!(fib(50, &mut memo, &mut calls), 12_586_269_025);
+        // Linear, not exponential: each n is expanded once
+        assert!(calls < 150, "too many calls: {}", calls);
+        assert_eq!(memo.len(), 49);
+    }
+
+    #[test]
+    fn test_memo_reuses_cached_results() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        let mut computed = 0;
+
+        for word in ["apple", "kiwi", "apple", "apple", "kiwi"] {
+            let len = memo.get_or_compute(word.to_string(), || {
+                computed += 1;
+                word.len()
+            });
+            assert_eq!(len, word.len());
+        }
+
+        assert_eq!(computed, 2);
+        assert_eq!(memo.len(), 2);
+    }
+
     #[test]
     fn test_find_extremes() {
         let numbers = vec![3, 1, 4, 1, 5, 9, 2, 6];
@@ -229,6 +632,25 @@ mod tests {
         assert_eq!(*max, 9);
     }
 
+    #[test]
+    fn test_bubble_sort() {
+        let mut numbers = [3, 1, 2];
+        assert!(!is_sorted(&numbers));
+        bubble_sort(&mut numbers);
+        assert_eq!(numbers, [1, 2, 3]);
+        assert!(is_sorted(&numbers));
+
+        let mut words = vec!["pear".to_string(), "apple".to_string(), "fig".to_string()];
+        bubble_sort(&mut words);
+        assert_eq!(words, vec!["apple", "fig", "pear"]);
+        assert!(is_sorted(&words));
+
+        let mut empty: [f64; 0] = [];
+        bubble_sort(&mut empty);
+        assert!(is_sorted(&empty));
+        assert!(is_sorted(&[7]));
+    }
+
     #[test]
     fn test_default_settings() {
         let settings: Settings<i32> = Settings::default();
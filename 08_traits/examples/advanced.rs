@@ -2,6 +2,7 @@
 //!
 //! Advanced trait patterns and object-oriented design
 
+use std::collections::HashMap;
 use std::fmt;
 
 /// Trait inheritance
@@ -48,6 +49,30 @@ pub fn animal_concert(animals: Vec<Box<dyn Animal>>) {
     }
 }
 
+/// Keyed registry of trait objects
+/// Shows: heterogeneous Box<dyn Trait> values in a HashMap
+#[derive(Default)]
+pub struct AnimalRegistry {
+    map: HashMap<String, Box<dyn Animal>>,
+}
+
+impl AnimalRegistry {
+    pub fn new() -> Self {
+        AnimalRegistry {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Replaces any animal already registered under `key`
+    pub fn register(&mut self, key: &str, animal: Box<dyn Animal>) {
+        self.map.insert(key.to_string(), animal);
+    }
+
+    pub fn get_sound(&self, key: &str) -> Option<String> {
+        self.map.get(key).map(|animal| animal.make_sound())
+    }
+}
+
 /// Associated types vs generics
 /// Shows: Output associated type
 pub trait Convertible {
@@ -175,6 +200,60 @@ impl Iterator for RangeIterator {
     }
 }
 
+/// 2-D IntoIterator with compound items
+/// Shows: row-major traversal yielding (row, col, value)
+pub struct Grid {
+    cells: Vec<Vec<i32>>,
+}
+
+impl Grid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Grid {
+            cells: vec![vec![0; cols]; rows],
+        }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: i32) {
+        self.cells[row][col] = value;
+    }
+}
+
+impl IntoIterator for Grid {
+    type Item = (usize, usize, i32);
+    type IntoIter = GridIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GridIterator {
+            cells: self.cells,
+            row: 0,
+            col: 0,
+        }
+    }
+}
+
+pub struct GridIterator {
+    cells: Vec<Vec<i32>>,
+    row: usize,
+    col: usize,
+}
+
+impl Iterator for GridIterator {
+    type Item = (usize, usize, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.row < self.cells.len() {
+            if self.col < self.cells[self.row].len() {
+                let item = (self.row, self.col, self.cells[self.row][self.col]);
+                self.col += 1;
+                return Some(item);
+            }
+            self.row += 1;
+            self.col = 0;
+        }
+        None
+    }
+}
+
 /// Blanket implementations
 /// Shows: impl<T: Trait> OtherTrait for T
 pub trait Printable {
@@ -195,6 +274,45 @@ fn double<T: Numeric>(x: T) -> T {
     x + x
 }
 
+/// Custom additive trait working through references
+/// Shows: associated constructor without self, contrast with std::iter::Sum
+pub trait Summable {
+    fn zero() -> Self;
+    fn add_ref(&self, other: &Self) -> Self;
+}
+
+impl Summable for i32 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn add_ref(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+impl Summable for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add_ref(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+pub fn sum_all<T: Summable>(items: &[T]) -> T {
+    items.iter().fold(T::zero(), |acc, item| acc.add_ref(item))
+}
+
+/// Sum of pairwise products; empty slices give `T::default()`
+pub fn dot_product<T: Numeric + Default>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len(), "dot_product requires slices of equal length");
+    a.iter()
+        .zip(b)
+        .fold(T::default(), |acc, (&x, &y)| acc + x * y)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +332,15 @@ mod tests {
         animal_concert(animals);
     }
 
+    #[test]
+    fn test_animal_registry() {
+        let mut registry = AnimalRegistry::new();
+        registry.register("dog", Box::new(Dog { name: "Buddy".to_string() }));
+
+        assert_eq!(registry.get_sound("dog"), Some("Woof!".to_string()));
+        assert_eq!(registry.get_sound("cat"), None);
+    }
+
     #[test]
     fn test_convertible() {
         let num: i32 = 42;
@@ -247,6 +374,19 @@ mod tests {
         assert_eq!(collected, vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_grid_into_iter() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(0, 1, 5);
+        grid.set(1, 0, 7);
+
+        let triples: Vec<_> = grid.into_iter().collect();
+        assert_eq!(triples, vec![(0, 0, 0), (0, 1, 5), (1, 0, 7), (1, 1, 0)]);
+
+        assert_eq!(Grid::new(0, 3).into_iter().count(), 0);
+        assert_eq!(Grid::new(3, 0).into_iter().count(), 0);
+    }
+
     #[test]
     fn test_printable_blanket() {
         let num = 42;
@@ -261,4 +401,25 @@ mod tests {
         assert_eq!(double(5), 10);
         assert_eq!(double(3.5), 7.0);
     }
+
+    #[test]
+    fn test_sum_all() {
+        assert_eq!(sum_all(&[1, 2, 3, 4]), 10);
+        assert_eq!(sum_all(&[0.5, 1.25, 2.25]), 4.0);
+        assert_eq!(sum_all::<i32>(&[]), i32::zero());
+        assert_eq!(sum_all::<f64>(&[]), f64::zero());
+    }
+
+    #[test]
+    fn test_dot_product() {
+        assert_eq!(dot_product(&[1, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(dot_product(&[1.5, 2.0], &[2.0, 0.25]), 3.5);
+        assert_eq!(dot_product::<i64>(&[], &[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn test_dot_product_length_mismatch() {
+        dot_product(&[1, 2], &[1]);
+    }
 }
\ No newline at end of file
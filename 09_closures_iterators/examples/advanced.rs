@@ -2,7 +2,8 @@
 //!
 //! Functional programming patterns
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 
 /// Iterator adapter chains
 /// Shows: map, filter, flat_map, collect
@@ -38,6 +39,20 @@ pub fn group_by_length(words: Vec<String>) -> HashMap<usize, Vec<String>> {
     })
 }
 
+/// Histogram with fixed-width buckets
+/// Shows: fold into an ordered map, entry API
+///
+/// Uses `div_euclid` so negatives land in the bucket below zero
+/// (-5 -> -10) instead of being rounded toward zero into bucket 0
+pub fn histogram(values: &[i32], bucket_size: i32) -> BTreeMap<i32, usize> {
+    assert!(bucket_size > 0, "bucket_size must be positive");
+    values.iter().fold(BTreeMap::new(), |mut buckets, &value| {
+        let bucket = value.div_euclid(bucket_size) * bucket_size;
+        *buckets.entry(bucket).or_insert(0) += 1;
+        buckets
+    })
+}
+
 /// Partitioning
 /// Shows: partition
 pub fn separate_even_odd(numbers: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
@@ -87,6 +102,14 @@ where
     f(f(x))
 }
 
+/// Apply `f` to `x` exactly `n` times (`n = 0` returns `x`)
+pub fn apply_n<F>(f: F, x: i32, n: u32) -> i32
+where
+    F: Fn(i32) -> i32,
+{
+    (0..n).fold(x, |acc, _| f(acc))
+}
+
 pub fn compose<F, G>(f: F, g: G) -> impl Fn(i32) -> i32
 where
     F: Fn(i32) -> i32,
@@ -95,6 +118,71 @@ where
     move |x| f(g(x))
 }
 
+/// Call `f` up to `attempts` times (at least once), returning the first Ok
+/// or the last Err
+/// Shows: FnMut capturing state across calls
+pub fn retry<T, E, F>(mut f: F, attempts: u32) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    let mut remaining = attempts.max(1);
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                remaining -= 1;
+                if remaining == 0 {
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
+/// Wrap a pure function so each distinct argument is computed only once
+/// Shows: returning FnMut that owns a HashMap cache
+pub fn memoize<A, R>(f: impl Fn(A) -> R) -> impl FnMut(A) -> R
+where
+    A: Eq + Hash + Clone,
+    R: Clone,
+{
+    let mut cache: HashMap<A, R> = HashMap::new();
+    move |arg| {
+        if let Some(result) = cache.get(&arg) {
+            return result.clone();
+        }
+        let result = f(arg.clone());
+        cache.insert(arg, result.clone());
+        result
+    }
+}
+
+/// Event dispatcher storing boxed closures
+/// Shows: Vec<Box<dyn Fn>>, registering callbacks
+#[derive(Default)]
+pub struct Dispatcher {
+    handlers: Vec<Handler>,
+}
+
+type Handler = Box<dyn Fn(&str)>;
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Dispatcher { handlers: Vec::new() }
+    }
+
+    pub fn on(&mut self, handler: impl Fn(&str) + 'static) {
+        self.handlers.push(Box::new(handler));
+    }
+
+    /// Call every registered handler in registration order
+    pub fn dispatch(&self, event: &str) {
+        for handler in &self.handlers {
+            handler(event);
+        }
+    }
+}
+
 /// Custom iterator adapter
 /// Shows: extending Iterator trait
 pub trait ChunkBy<T>: Iterator<Item = T> + Sized {
@@ -134,7 +222,7 @@ where
             match self.iter.next() {
                 Some(item) => {
                     if let Some(ref mut current) = self.current {
-                        if (self.f)(¤t.last().unwrap(), &item) {
+                        if (self.f)(current.last().unwrap(), &item) {
                             current.push(item);
                             continue;
                         } else {
@@ -163,6 +251,41 @@ pub fn running_sum(numbers: Vec<i32>) -> Vec<i32> {
         .collect()
 }
 
+/// Average of each contiguous window of `window` values
+/// Shows: slice windows(), map + sum
+pub fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > values.len() {
+        return Vec::new();
+    }
+    values
+        .windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/// Deepest `(` nesting, or None if the brackets are unbalanced
+/// Shows: fold carrying (current_depth, max_depth, valid) state
+pub fn max_nesting_depth(s: &str) -> Option<usize> {
+    let (depth, max_depth, valid) = s.chars().fold((0usize, 0usize, true), |state, c| {
+        let (depth, max_depth, valid) = state;
+        if !valid {
+            return state;
+        }
+        match c {
+            '(' => (depth + 1, max_depth.max(depth + 1), true),
+            ')' if depth == 0 => (0, max_depth, false),
+            ')' => (depth - 1, max_depth, true),
+            _ => state,
+        }
+    });
+
+    if valid && depth == 0 {
+        Some(max_depth)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +313,21 @@ mod tests {
         assert_eq!(grouped.get(&3).unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_histogram() {
+        let hist = histogram(&[1, 2, 3, 10, 11], 10);
+        assert_eq!(hist, BTreeMap::from([(0, 3), (10, 2)]));
+
+        let hist = histogram(&[-5, -10, 5], 10);
+        assert_eq!(hist, BTreeMap::from([(-10, 2), (0, 1)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket_size must be positive")]
+    fn test_histogram_zero_bucket() {
+        histogram(&[1], 0);
+    }
+
     #[test]
     fn test_separate_even_odd() {
         let nums = vec![1, 2, 3, 4, 5, 6];
@@ -219,6 +357,14 @@ mod tests {
         assert_eq!(apply_twice(double, 5), 20);  // (5*2)*2
     }
 
+    #[test]
+    fn test_apply_n() {
+        let double = |x| x * 2;
+        assert_eq!(apply_n(double, 1, 3), 8);  // ((1*2)*2)*2
+        assert_eq!(apply_n(double, 5, 0), 5);
+        assert_eq!(apply_n(double, 5, 2), apply_twice(double, 5));
+    }
+
     #[test]
     fn test_compose() {
         let add_one = |x| x + 1;
@@ -227,6 +373,91 @@ mod tests {
         assert_eq!(add_then_double(5), 12);  // (5+1)*2
     }
 
+    #[test]
+    fn test_retry_succeeds_on_second_try() {
+        let mut calls = 0;
+        let result: Result<&str, &str> = retry(
+            || {
+                calls += 1;
+                if calls < 2 {
+                    Err("not yet")
+                } else {
+                    Ok("done")
+                }
+            },
+            3,
+        );
+        assert_eq!(result, Ok("done"));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_retry_always_fails() {
+        let mut calls = 0;
+        let result: Result<(), String> = retry(
+            || {
+                calls += 1;
+                Err(format!("failure {}", calls))
+            },
+            3,
+        );
+        assert_eq!(result, Err("failure 3".to_string()));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_memoize() {
+        use std::cell::Cell;
+
+        let computations = Cell::new(0);
+        let mut square = memoize(|x: u64| {
+            computations.set(computations.get() + 1);
+            x * x
+        });
+
+        assert_eq!(square(12), 144);
+        assert_eq!(square(12), 144);
+        assert_eq!(computations.get(), 1);
+
+        assert_eq!(square(3), 9);
+        assert_eq!(computations.get(), 2);
+    }
+
+    #[test]
+    fn test_dispatcher() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut dispatcher = Dispatcher::new();
+
+        let first = Rc::clone(&log);
+        dispatcher.on(move |event| first.borrow_mut().push(format!("first: {}", event)));
+        let second = Rc::clone(&log);
+        dispatcher.on(move |event| second.borrow_mut().push(format!("second: {}", event)));
+
+        dispatcher.dispatch("click");
+
+        assert_eq!(*log.borrow(), vec!["first: click", "second: click"]);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 2), vec![1.5, 2.5, 3.5]);
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 4), vec![2.5]);
+        assert!(moving_average(&[1.0, 2.0], 0).is_empty());
+        assert!(moving_average(&[1.0, 2.0], 3).is_empty());
+    }
+
+    #[test]
+    fn test_max_nesting_depth() {
+        assert_eq!(max_nesting_depth("((()))"), Some(3));
+        assert_eq!(max_nesting_depth("(a)(b(c))"), Some(2));
+        assert_eq!(max_nesting_depth("no brackets"), Some(0));
+        assert_eq!(max_nesting_depth(")("), None);
+        assert_eq!(max_nesting_depth("(()"), None);
+    }
+
     #[test]
     fn test_running_sum() {
         let nums = vec![1, 2, 3, 4, 5];
@@ -2,6 +2,7 @@
 //!
 //! Real-world async and parallel patterns
 
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex, RwLock, mpsc};
 use std::thread;
 use std::time::Duration;
@@ -48,11 +49,88 @@ impl<T: Send + 'static> Actor<T> {
     }
 }
 
+/// Request/response actor: every request carries its own reply channel
+/// Shows: oneshot-style reply channels, blocking on a response
+pub struct RequestActor<Req, Resp> {
+    sender: mpsc::Sender<(Req, mpsc::Sender<Resp>)>,
+    handle: thread::JoinHandle<()>,
+}
+
+impl<Req: Send + 'static, Resp: Send + 'static> RequestActor<Req, Resp> {
+    pub fn new<F>(mut processor: F) -> Self
+    where
+        F: FnMut(Req) -> Resp + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel::<(Req, mpsc::Sender<Resp>)>();
+
+        let handle = thread::spawn(move || {
+            while let Ok((req, reply)) = receiver.recv() {
+                // Caller may have given up waiting; that's not our problem
+                let _ = reply.send(processor(req));
+            }
+        });
+
+        RequestActor { sender, handle }
+    }
+
+    pub fn request(&self, req: Req) -> Result<Resp, &'static str> {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        self.sender
+            .send((req, reply_tx))
+            .map_err(|_| "actor stopped")?;
+        reply_rx.recv().map_err(|_| "actor dropped reply")
+    }
+
+    pub fn stop(self) {
+        // Closing the request channel ends the actor's loop
+        drop(self.sender);
+        let _ = self.handle.join();
+    }
+}
+
+/// Broadcast pub/sub over one channel per subscriber
+/// Shows: fan-out by cloning, pruning senders whose receiver hung up
+pub struct EventBus<T: Clone + Send + 'static> {
+    subscribers: Mutex<Vec<mpsc::Sender<T>>>,
+}
+
+impl<T: Clone + Send + 'static> EventBus<T> {
+    pub fn new() -> Self {
+        EventBus {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn subscribe(&self) -> mpsc::Receiver<T> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Send a clone to every live subscriber; dead ones are removed
+    pub fn publish(&self, event: T) {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+}
+
+impl<T: Clone + Send + 'static> Default for EventBus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Thread pool with work stealing concept
 /// Shows: Arc<Mutex>, condition variables, thread management
 pub struct ThreadPool {
     workers: Vec<Worker>,
-    sender: mpsc::Sender<Job>,
+    sender: Option<mpsc::Sender<Job>>,
 }
 
 type Job = Box<dyn FnOnce() + Send + 'static>;
@@ -67,7 +145,7 @@ impl ThreadPool {
             workers.push(Worker::new(id, Arc::clone(&receiver)));
         }
         
-        ThreadPool { workers, sender }
+        ThreadPool { workers, sender: Some(sender) }
     }
 
     pub fn execute<F>(&self, f: F)
@@ -75,7 +153,7 @@ impl ThreadPool {
         F: FnOnce() + Send + 'static,
     {
         let job = Box::new(f);
-        self.sender.send(job).unwrap();
+        self.sender.as_ref().unwrap().send(job).unwrap();
     }
 }
 
@@ -124,28 +202,88 @@ impl Worker {
 }
 
 /// Read-heavy data structure with RwLock
-/// Shows: multiple readers, single writer
+/// Shows: multiple readers, single writer, bounded size with eviction
 pub struct Cache<K, V> {
     data: RwLock<std::collections::HashMap<K, V>>,
+    // Insertion order, only touched while the write lock is held
+    order: Mutex<VecDeque<K>>,
+    capacity: usize,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
 }
 
-impl<K: Eq + std::hash::Hash, V: Clone> Cache<K, V> {
+impl<K: Eq + std::hash::Hash + Clone, V: Clone> Cache<K, V> {
     pub fn new() -> Self {
+        Self::with_capacity(usize::MAX)
+    }
+
+    /// Cache that evicts the oldest inserted key once it holds more than `cap` entries
+    pub fn with_capacity(cap: usize) -> Self {
         Cache {
             data: RwLock::new(std::collections::HashMap::new()),
+            order: Mutex::new(VecDeque::new()),
+            capacity: cap,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
         }
     }
 
     pub fn get(&self, key: &K) -> Option<V> {
         // Many threads can hold read lock simultaneously
         let map = self.data.read().unwrap();
-        map.get(key).cloned()
+        let value = map.get(key).cloned();
+        // Counters are atomic, so updating them under a read lock is fine
+        match value {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        value
+    }
+
+    /// Return the cached value, or compute and store it on a miss
+    pub fn get_or_compute<F>(&self, key: K, compute: F) -> V
+    where
+        F: FnOnce() -> V,
+    {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute();
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// (hits, misses) observed by `get`
+    pub fn stats(&self) -> (usize, usize) {
+        (
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        )
     }
 
     pub fn insert(&self, key: K, value: V) {
         // Only one thread can hold write lock
         let mut map = self.data.write().unwrap();
-        map.insert(key, value);
+        let mut order = self.order.lock().unwrap();
+        if map.insert(key.clone(), value).is_none() {
+            order.push_back(key);
+        }
+        while map.len() > self.capacity {
+            match order.pop_front() {
+                Some(oldest) => {
+                    map.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
@@ -192,6 +330,61 @@ pub fn parallel_sum(data: &[usize]) -> usize {
     })
 }
 
+/// Count matching items with one scoped thread per chunk
+/// Shows: available_parallelism, chunked fan-out, join-and-sum
+pub fn parallel_count_if<T, P>(data: &[T], pred: P) -> usize
+where
+    T: Sync,
+    P: Fn(&T) -> bool + Sync,
+{
+    if data.len() < 1000 {
+        return data.iter().filter(|x| pred(x)).count();
+    }
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = data.len().div_ceil(threads);
+    let pred = &pred;
+
+    thread::scope(|s| {
+        let handles: Vec<_> = data
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().filter(|x| pred(x)).count()))
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+/// Fold a slice across `workers` scoped threads, then fold the partial results
+/// Shows: generic parallel reduction; `combine` must be associative and
+/// `identity` neutral, since each chunk starts from its own copy of it
+pub fn parallel_reduce<T, F>(data: &[T], identity: T, combine: F, workers: usize) -> T
+where
+    T: Send + Sync + Clone,
+    F: Fn(T, &T) -> T + Sync,
+{
+    if data.len() < 1000 || workers <= 1 {
+        return data.iter().fold(identity, &combine);
+    }
+
+    let chunk_size = data.len().div_ceil(workers);
+    let combine = &combine;
+
+    let partials: Vec<T> = thread::scope(|s| {
+        let handles: Vec<_> = data
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let init = identity.clone();
+                s.spawn(move || chunk.iter().fold(init, combine))
+            })
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    partials.iter().fold(identity, combine)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +403,17 @@ mod tests {
         actor.stop();
     }
 
+    #[test]
+    fn test_request_actor() {
+        let actor = RequestActor::new(|msg: String| msg.len());
+
+        assert_eq!(actor.request("Hello".to_string()), Ok(5));
+        assert_eq!(actor.request(String::new()), Ok(0));
+        assert_eq!(actor.request("สวัสดี".to_string()), Ok(18));
+
+        actor.stop();
+    }
+
     #[test]
     fn test_thread_pool() {
         let pool = ThreadPool::new(4);
@@ -237,6 +441,62 @@ mod tests {
         assert_eq!(cache.get(&"key2"), Some("value2".to_string()));
     }
 
+    #[test]
+    fn test_cache_stats() {
+        let cache = Cache::new();
+        cache.insert("present", 1);
+
+        cache.get(&"present");
+        cache.get(&"present");
+        cache.get(&"absent");
+
+        assert_eq!(cache.stats(), (2, 1));
+    }
+
+    #[test]
+    fn test_cache_get_or_compute() {
+        let cache = Cache::new();
+        assert_eq!(cache.get_or_compute("answer", || 42), 42);
+        assert_eq!(cache.get_or_compute("answer", || 0), 42);
+        assert_eq!(cache.stats(), (1, 1));
+    }
+
+    #[test]
+    fn test_cache_eviction() {
+        let cache = Cache::with_capacity(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.insert("c", 3);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(2));
+        assert_eq!(cache.get(&"c"), Some(3));
+    }
+
+    #[test]
+    fn test_cache_bounded_concurrent() {
+        let cap = 16;
+        let cache = Arc::new(Cache::with_capacity(cap));
+        let mut handles = vec![];
+
+        for t in 0..4 {
+            let c = Arc::clone(&cache);
+            handles.push(thread::spawn(move || {
+                for i in 0..100 {
+                    c.insert(t * 100 + i, i);
+                    assert!(c.len() <= cap);
+                }
+            }));
+        }
+
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        assert_eq!(cache.len(), cap);
+    }
+
     #[test]
     fn test_counter() {
         let counter = Arc::new(Counter::new());
@@ -265,6 +525,62 @@ mod tests {
         assert_eq!(sum, data.iter().sum::<usize>());
     }
 
+    #[test]
+    fn test_parallel_count_if() {
+        let data: Vec<u32> = (0..10_000).collect();
+        let serial = data.iter().filter(|x| *x % 2 == 0).count();
+
+        assert_eq!(parallel_count_if(&data, |x| x % 2 == 0), serial);
+        assert_eq!(parallel_count_if(&data[..10], |x| x % 2 == 0), 5);
+        assert_eq!(parallel_count_if(&[] as &[u32], |_| true), 0);
+    }
+
+    #[test]
+    fn test_parallel_reduce() {
+        let small: Vec<u64> = (1..=10).collect();
+        let product = parallel_reduce(&small, 1, |acc, x| acc * x, 4);
+        assert_eq!(product, small.iter().product::<u64>());
+
+        let large: Vec<u64> = (0..50_000).collect();
+        let serial: u64 = large.iter().sum();
+        assert_eq!(parallel_reduce(&large, 0, |acc, x| acc + x, 4), serial);
+        assert_eq!(parallel_reduce(&large, 0, |acc, x| acc + x, 0), serial);
+        assert_eq!(parallel_reduce(&[] as &[u64], 0, |acc, x| acc + x, 4), 0);
+    }
+
+    #[test]
+    fn test_event_bus() {
+        let bus = EventBus::new();
+        let first = bus.subscribe();
+        let second = bus.subscribe();
+        let dropped = bus.subscribe();
+        drop(dropped);
+        assert_eq!(bus.subscriber_count(), 3);
+
+        bus.publish("deployed".to_string());
+
+        assert_eq!(first.recv().unwrap(), "deployed");
+        assert_eq!(second.recv().unwrap(), "deployed");
+        assert_eq!(bus.subscriber_count(), 2);
+    }
+
+    #[test]
+    fn test_event_bus_across_threads() {
+        let bus = Arc::new(EventBus::new());
+        let rx = bus.subscribe();
+
+        let publisher = Arc::clone(&bus);
+        thread::spawn(move || {
+            for i in 0..3 {
+                publisher.publish(i);
+            }
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(rx.try_iter().collect::<Vec<i32>>(), vec![0, 1, 2]);
+    }
+
     #[test]
     fn test_barrier() {
         let barrier = Arc::new(Barrier::new(3));
@@ -1,34 +1,84 @@
 //! Lesson 10: Concurrency
 
-use std::sync::{Arc, Mutex};
+use std::sync::Mutex;
 use std::thread;
 
 /// สร้าง threads หลายตัวเพื่อคำนวณผลรวม
+/// - input ว่างคืน 0 โดยไม่สร้าง thread
+/// - จำนวน thread ถูกจำกัดไว้ที่ 1..=numbers.len() จึงไม่มี thread ที่ได้ชิ้นว่าง
+/// - แบ่ง slice ก่อน spawn แต่ละ thread ยืมชิ้นของตัวเอง ไม่ต้องแย่ง lock กัน
 pub fn parallel_sum(numbers: Vec<i32>, num_threads: usize) -> i32 {
-    let chunk_size = numbers.len() / num_threads;
-    let numbers = Arc::new(Mutex::new(numbers));
-    let mut handles = vec![];
-    
-    for i in 0..num_threads {
-        let numbers = Arc::clone(&numbers);
-        let handle = thread::spawn(move || {
-            let nums = numbers.lock().unwrap();
-            let start = i * chunk_size;
-            let end = if i == num_threads - 1 {
-                nums.len()
-            } else {
-                start + chunk_size
-            };
-            nums[start..end].iter().sum::<i32>()
-        });
-        handles.push(handle);
-    }
-    
-    let mut total = 0;
-    for handle in handles {
-        total += handle.join().unwrap();
-    }
-    total
+    if numbers.is_empty() {
+        return 0;
+    }
+    let num_threads = num_threads.clamp(1, numbers.len());
+    
+    thread::scope(|s| {
+        let handles: Vec<_> = split_into_chunks(&numbers, num_threads)
+            .into_iter()
+            .map(|chunk| s.spawn(move || chunk.iter().sum::<i32>()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+/// ใช้ `f` กับทุกตัวแบบขนาน แล้วคืนผลตามลำดับเดิมของ input
+/// แต่ละ thread ได้ชิ้นต่อเนื่องของ items ไปเป็นเจ้าของ และ join ตามลำดับชิ้น
+/// ผลจึงเรียงถูกแม้ thread จะเสร็จไม่พร้อมกัน
+/// ใช้ thread::scope จึงไม่ต้องการ `'static`
+pub fn parallel_map<T, U, F>(items: Vec<T>, num_threads: usize, f: F) -> Vec<U>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> U + Send + Sync + Clone,
+{
+    if items.is_empty() {
+        return Vec::new();
+    }
+    let num_threads = num_threads.clamp(1, items.len());
+    
+    // ขนาดชิ้นเหมือน split_into_chunks แต่ย้าย ownership ของแต่ละตัวออกมาด้วย
+    let base = items.len() / num_threads;
+    let remainder = items.len() % num_threads;
+    let mut items = items.into_iter();
+    let chunks: Vec<Vec<T>> = (0..num_threads)
+        .map(|i| {
+            let size = base + if i < remainder { 1 } else { 0 };
+            items.by_ref().take(size).collect()
+        })
+        .collect();
+    
+    thread::scope(|s| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                let f = f.clone();
+                s.spawn(move || chunk.into_iter().map(f).collect::<Vec<U>>())
+            })
+            .collect();
+        handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+    })
+}
+
+/// แบ่ง slice เป็น n ชิ้นต่อเนื่องกัน ขนาดต่างกันไม่เกิน 1
+/// เศษที่เหลือแจกให้ชิ้นแรกๆ ก่อน เช่น 10 ตัวแบ่ง 3 → 4, 3, 3
+/// - n == 0 คืน Vec ว่าง
+/// - n > len คืนครบ n ชิ้นเสมอ โดยชิ้นท้ายๆ จะว่าง
+pub fn split_into_chunks<T>(slice: &[T], n: usize) -> Vec<&[T]> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let base = slice.len() / n;
+    let remainder = slice.len() % n;
+    
+    let mut chunks = Vec::with_capacity(n);
+    let mut start = 0;
+    for i in 0..n {
+        let size = base + if i < remainder { 1 } else { 0 };
+        chunks.push(&slice[start..start + size]);
+        start += size;
+    }
+    chunks
 }
 
 /// Counter ที่ thread-safe
@@ -48,9 +98,43 @@ impl Counter {
         *v += 1;
     }
     
+    /// ลดค่าลง 1 ค่าเป็น i32 จึงติดลบได้ ไม่หยุดที่ 0
+    pub fn decrement(&self) {
+        let mut v = self.value.lock().unwrap();
+        *v -= 1;
+    }
+    
+    /// ปรับค่าทีละ n (ติดลบได้) ด้วยการ lock ครั้งเดียว
+    /// ต่างจากเรียก increment n ครั้ง ที่ thread อื่นแทรกระหว่างกลางได้
+    pub fn add(&self, n: i32) {
+        let mut v = self.value.lock().unwrap();
+        *v += n;
+    }
+    
+    /// ตั้งค่ากลับเป็น 0
+    pub fn reset(&self) {
+        *self.value.lock().unwrap() = 0;
+    }
+    
+    /// เพิ่มค่าแบบไม่รอ lock คืน false ถ้ามี thread อื่นถือ lock อยู่
+    pub fn try_increment(&self) -> bool {
+        match self.value.try_lock() {
+            Ok(mut v) => {
+                *v += 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+    
     pub fn get(&self) -> i32 {
         *self.value.lock().unwrap()
     }
+    
+    /// อ่านค่าได้แม้ mutex จะ poisoned (มี thread panic ระหว่างถือ lock)
+    pub fn get_recovering(&self) -> i32 {
+        *self.value.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 /// ส่งข้อมูลผ่าน channel
@@ -66,10 +150,37 @@ pub fn send_and_receive() -> i32 {
     rx.recv().unwrap()
 }
 
+/// รัน `f` ใน thread ใหม่ แล้วรอผลไม่เกิน `timeout_ms` มิลลิวินาที
+/// ถ้าหมดเวลา thread จะยังทำงานต่อเบื้องหลัง แต่ผลลัพธ์จะถูกทิ้ง
+/// ถ้า `f` panic จะได้ Err("worker panicked") ทันทีโดยไม่ต้องรอจนหมดเวลา
+pub fn run_with_timeout<T, F>(f: F, timeout_ms: u64) -> Result<T, &'static str>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::time::Duration;
+    
+    let (tx, rx) = mpsc::channel();
+    
+    thread::spawn(move || {
+        // ผู้เรียกอาจเลิกรอไปแล้ว จึงไม่สนใจ error ตอนส่ง
+        let _ = tx.send(f());
+    });
+    
+    // thread ที่ panic จะ drop tx ไปโดยไม่ได้ส่ง channel จึงกลายเป็น Disconnected
+    rx.recv_timeout(Duration::from_millis(timeout_ms))
+        .map_err(|e| match e {
+            RecvTimeoutError::Timeout => "timed out",
+            RecvTimeoutError::Disconnected => "worker panicked",
+        })
+}
+
 // TESTS
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
 
     #[test]
     fn test_parallel_sum() {
@@ -78,6 +189,112 @@ mod tests {
         assert_eq!(result, 5050);
     }
 
+    #[test]
+    fn test_parallel_sum_empty() {
+        assert_eq!(parallel_sum(Vec::new(), 4), 0);
+        assert_eq!(parallel_sum(Vec::new(), 0), 0);
+    }
+
+    #[test]
+    fn test_parallel_sum_fewer_items_than_threads() {
+        assert_eq!(parallel_sum(vec![7], 4), 7);
+        assert_eq!(parallel_sum(vec![1, 2, 3], 0), 6);
+    }
+
+    #[test]
+    fn test_parallel_sum_uneven_chunks() {
+        let numbers: Vec<i32> = (1..=10).collect();
+        for threads in 1..=12 {
+            assert_eq!(parallel_sum(numbers.clone(), threads), 55, "threads = {}", threads);
+        }
+    }
+
+    #[test]
+    fn test_parallel_map_preserves_order() {
+        let numbers: Vec<i64> = (1..=10).collect();
+        let expected: Vec<i64> = numbers.iter().map(|n| n * n).collect();
+        for threads in [0, 1, 3, 4, 10, 16] {
+            let squares = parallel_map(numbers.clone(), threads, |n| n * n);
+            assert_eq!(squares, expected, "threads = {}", threads);
+        }
+    }
+
+    #[test]
+    fn test_parallel_map_empty_and_borrowing() {
+        assert!(parallel_map(Vec::<i32>::new(), 4, |n| n * n).is_empty());
+        
+        // closure ยืมข้อมูลจาก stack ได้เพราะใช้ scoped threads
+        let offset = String::from("id-");
+        let labels = parallel_map(vec![1, 2, 3], 2, |n| format!("{}{}", offset, n));
+        assert_eq!(labels, vec!["id-1", "id-2", "id-3"]);
+    }
+
+    #[test]
+    fn test_counter_decrement_add_reset() {
+        let counter = Counter::new();
+        for _ in 0..5 {
+            counter.increment();
+        }
+        counter.decrement();
+        assert_eq!(counter.get(), 4);
+        
+        counter.add(-7);
+        assert_eq!(counter.get(), -3);
+        counter.decrement();
+        assert_eq!(counter.get(), -4);
+        
+        counter.reset();
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    fn test_counter_add_across_threads() {
+        let counter = Arc::new(Counter::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    counter.add(10);
+                    counter.decrement();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.get(), 36);
+    }
+
+    #[test]
+    fn test_split_into_chunks_even() {
+        let data: Vec<i32> = (1..=6).collect();
+        let chunks = split_into_chunks(&data, 3);
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+    }
+
+    #[test]
+    fn test_split_into_chunks_uneven() {
+        let data: Vec<i32> = (1..=10).collect();
+        let chunks = split_into_chunks(&data, 3);
+        let sizes: Vec<usize> = chunks.iter().map(|c| c.len()).collect();
+        assert_eq!(sizes, vec![4, 3, 3]);
+        assert_eq!(chunks.concat(), data);
+    }
+
+    #[test]
+    fn test_split_into_chunks_edge_cases() {
+        let data = [1, 2];
+        assert!(split_into_chunks(&data, 0).is_empty());
+        
+        let chunks = split_into_chunks(&data, 4);
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.concat(), data);
+        assert!(chunks[2].is_empty() && chunks[3].is_empty());
+        
+        let empty: [i32; 0] = [];
+        assert_eq!(split_into_chunks(&empty, 2), vec![&[][..], &[]]);
+    }
+
     #[test]
     fn test_counter() {
         let counter = Counter::new();
@@ -86,8 +303,65 @@ mod tests {
         assert_eq!(counter.get(), 2);
     }
 
+    #[test]
+    fn test_try_increment() {
+        let counter = Counter::new();
+        assert!(counter.try_increment());
+
+        let guard = counter.value.lock().unwrap();
+        assert!(!counter.try_increment());
+        drop(guard);
+
+        assert!(counter.try_increment());
+        assert_eq!(counter.get(), 2);
+    }
+
+    #[test]
+    fn test_get_recovering() {
+        let counter = Arc::new(Counter::new());
+        counter.increment();
+
+        let c = Arc::clone(&counter);
+        let result = thread::spawn(move || {
+            let _guard = c.value.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+
+        assert!(result.is_err());
+        assert!(counter.value.is_poisoned());
+        assert_eq!(counter.get_recovering(), 1);
+    }
+
     #[test]
     fn test_send_and_receive() {
         assert_eq!(send_and_receive(), 42);
     }
+
+    #[test]
+    fn test_run_with_timeout_fast() {
+        assert_eq!(run_with_timeout(|| 6 * 7, 1000), Ok(42));
+    }
+
+    #[test]
+    fn test_run_with_timeout_slow() {
+        let result = run_with_timeout(
+            || {
+                thread::sleep(std::time::Duration::from_millis(500));
+                42
+            },
+            10,
+        );
+        assert_eq!(result, Err("timed out"));
+    }
+
+    #[test]
+    fn test_run_with_timeout_panic() {
+        use std::time::{Duration, Instant};
+        
+        let start = Instant::now();
+        let result = run_with_timeout(|| -> u32 { panic!("boom") }, 10_000);
+        assert_eq!(result, Err("worker panicked"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
 }
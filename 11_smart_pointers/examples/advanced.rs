@@ -2,7 +2,8 @@
 //!
 //! Custom smart pointers and interior mutability patterns
 
-use std::ops::Deref;
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -59,6 +60,13 @@ impl SharedState {
         self.data.borrow_mut().push(value);
     }
 
+    /// Like `add`, but reports a borrow conflict instead of panicking
+    pub fn try_add(&self, value: i32) -> Result<(), &'static str> {
+        let mut data = self.data.try_borrow_mut().map_err(|_| "already borrowed")?;
+        data.push(value);
+        Ok(())
+    }
+
     pub fn count(&self) -> usize {
         self.data.borrow().len()
     }
@@ -99,6 +107,64 @@ impl Node {
             .and_then(|weak| weak.upgrade())
             .map(|parent| parent.value)
     }
+
+    /// Other children of this node's parent, compared by pointer identity
+    pub fn siblings(self: &Rc<Self>) -> Vec<Rc<Node>> {
+        let parent = match self.parent.borrow().as_ref().and_then(|weak| weak.upgrade()) {
+            Some(parent) => parent,
+            None => return Vec::new(),
+        };
+        let siblings = parent
+            .children
+            .borrow()
+            .iter()
+            .filter(|child| !Rc::ptr_eq(child, self))
+            .cloned()
+            .collect();
+        siblings
+    }
+}
+
+/// Owned generic tree, no Rc needed when there are no parent links
+/// Shows: recursive ownership, DFS with a stack, BFS with VecDeque
+pub struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(value: T) -> Self {
+        Tree {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(value: T, children: Vec<Tree<T>>) -> Self {
+        Tree { value, children }
+    }
+
+    /// Pre-order: node first, then each child subtree left to right
+    pub fn dfs(&self) -> Vec<&T> {
+        let mut out = Vec::new();
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            out.push(&node.value);
+            stack.extend(node.children.iter().rev());
+        }
+        out
+    }
+
+    /// Level by level, left to right
+    pub fn bfs(&self) -> Vec<&T> {
+        let mut out = Vec::new();
+        let mut queue = VecDeque::from([self]);
+        while let Some(node) = queue.pop_front() {
+            out.push(&node.value);
+            queue.extend(&node.children);
+        }
+        out
+    }
 }
 
 /// Memory-efficient storage with Cow
@@ -129,6 +195,17 @@ impl ConfigValue {
     pub fn as_str(&self) -> &str {
         &self.data
     }
+
+    /// Trimmed, lowercase view that only allocates when something changes
+    pub fn normalized(&self) -> Cow<'_, str> {
+        let s: &str = &self.data;
+        let trimmed = s.trim();
+        if trimmed.len() == s.len() && !s.chars().any(char::is_uppercase) {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(trimmed.to_lowercase())
+        }
+    }
 }
 
 /// Lazy initialization with OnceCell
@@ -152,6 +229,26 @@ pub struct Config {
     max_connections: u32,
 }
 
+/// Reusable lazy value: runs `init` once, on first access
+/// Shows: OnceLock::get_or_init outside of a static
+pub struct Lazy<T> {
+    cell: OnceLock<T>,
+    init: fn() -> T,
+}
+
+impl<T> Lazy<T> {
+    pub fn new(init: fn() -> T) -> Self {
+        Lazy {
+            cell: OnceLock::new(),
+            init,
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        self.cell.get_or_init(self.init)
+    }
+}
+
 /// Pin for self-referential structs
 /// Shows: Pin, why we need it for async
 use std::pin::Pin;
@@ -195,6 +292,20 @@ mod tests {
         assert_eq!(state2.count(), 2);
     }
 
+    #[test]
+    fn test_shared_state_try_add_conflict() {
+        let state = SharedState::new();
+        let other = state.clone_ref();
+
+        {
+            let _reader = other.data.borrow();
+            assert_eq!(state.try_add(1), Err("already borrowed"));
+        }
+
+        assert_eq!(state.try_add(1), Ok(()));
+        assert_eq!(state.count(), 1);
+    }
+
     #[test]
     fn test_node_tree() {
         let root = Node::new(1);
@@ -211,6 +322,46 @@ mod tests {
         // No cycles thanks to Weak references
     }
 
+    #[test]
+    fn test_node_siblings() {
+        let parent = Node::new(0);
+        let children: Vec<_> = (1..=3).map(Node::new).collect();
+        for child in &children {
+            parent.add_child(child);
+        }
+
+        for child in &children {
+            let mut values: Vec<i32> = child.siblings().iter().map(|s| s.value).collect();
+            values.sort();
+            let expected: Vec<i32> = (1..=3).filter(|v| *v != child.value).collect();
+            assert_eq!(values, expected);
+        }
+
+        assert!(parent.siblings().is_empty());
+    }
+
+    #[test]
+    fn test_tree_traversal_orders() {
+        //        root
+        //       /    \
+        //      a      b
+        //     / \      \
+        //    a1  a2     b1
+        let tree = Tree::with_children(
+            "root",
+            vec![
+                Tree::with_children("a", vec![Tree::new("a1"), Tree::new("a2")]),
+                Tree::with_children("b", vec![Tree::new("b1")]),
+            ],
+        );
+
+        assert_eq!(tree.dfs(), vec![&"root", &"a", &"a1", &"a2", &"b", &"b1"]);
+        assert_eq!(tree.bfs(), vec![&"root", &"a", &"b", &"a1", &"a2", &"b1"]);
+
+        let leaf = Tree::new(String::from("only"));
+        assert_eq!(leaf.dfs(), leaf.bfs());
+    }
+
     #[test]
     fn test_config_value() {
         let mut config = ConfigValue::from_static("default");
@@ -222,6 +373,17 @@ mod tests {
         assert_eq!(config.as_str(), "custom");
     }
 
+    #[test]
+    fn test_config_value_normalized() {
+        let clean = ConfigValue::from_static("hello");
+        assert!(matches!(clean.normalized(), Cow::Borrowed("hello")));
+
+        let messy = ConfigValue::from_static("  HELLO ");
+        let normalized = messy.normalized();
+        assert!(matches!(normalized, Cow::Owned(_)));
+        assert_eq!(normalized, "hello");
+    }
+
     #[test]
     fn test_lazy_settings() {
         let config1 = Settings::get_config();
@@ -231,10 +393,29 @@ mod tests {
         assert_eq!(config1.max_connections, config2.max_connections);
     }
 
+    #[test]
+    fn test_lazy_runs_init_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static INIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn init() -> Vec<u32> {
+            INIT_CALLS.fetch_add(1, Ordering::SeqCst);
+            vec![1, 2, 3]
+        }
+
+        let lazy = Lazy::new(init);
+        assert_eq!(INIT_CALLS.load(Ordering::SeqCst), 0);
+
+        assert_eq!(lazy.get(), &vec![1, 2, 3]);
+        assert_eq!(lazy.get().len(), 3);
+        assert_eq!(INIT_CALLS.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_self_referential() {
         let sr = SelfReferential::new("hello".to_string());
-        assert_eq!(sr.data(), "hello");
+        assert_eq!(sr.as_ref().data(), "hello");
     }
 
     #[test]
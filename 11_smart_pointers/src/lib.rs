@@ -27,6 +27,85 @@ impl List {
     }
 }
 
+/// linked list แบบ generic ที่ต่อ node ด้วย Box
+/// ต่างจาก `List` ตรงที่เก็บค่าอะไรก็ได้ และ drop ได้โดยไม่ stack overflow
+pub struct LinkedList<T> {
+    head: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+struct Node<T> {
+    value: T,
+    next: Option<Box<Node<T>>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+    
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+    
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+    
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    
+    /// วนค่าจากหัวไปท้าย
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// drop แบบ recursive ตามปกติจะเรียกซ้อนกันหนึ่งชั้นต่อ node
+/// list ยาวๆ จึง stack overflow ได้ เลยถอด node ออกทีละตัวด้วย loop แทน
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
 /// ใช้ Rc สำหรับ shared ownership
 pub struct SharedData {
     pub value: Rc<RefCell<i32>>,
@@ -59,6 +138,38 @@ mod tests {
         assert_eq!(list.sum(), 6);
     }
 
+    #[test]
+    fn test_linked_list_push_pop() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+        
+        list.push_front("c".to_string());
+        list.push_front("b".to_string());
+        list.push_front("a".to_string());
+        assert_eq!(list.len(), 3);
+        
+        let items: Vec<&String> = list.iter().collect();
+        assert_eq!(items, vec!["a", "b", "c"]);
+        
+        assert_eq!(list.pop_front().as_deref(), Some("a"));
+        assert_eq!(list.pop_front().as_deref(), Some("b"));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop_front().as_deref(), Some("c"));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_linked_list_long_drop() {
+        let mut list = LinkedList::new();
+        for i in 0..100_000 {
+            list.push_front(i);
+        }
+        assert_eq!(list.len(), 100_000);
+        assert_eq!(list.iter().next(), Some(&99_999));
+        drop(list);
+    }
+
     #[test]
     fn test_shared_data() {
         let data = SharedData::new(5);
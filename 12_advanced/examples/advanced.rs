@@ -41,6 +41,20 @@ impl UnsafeBuffer {
     pub fn as_slice(&self) -> &[u8] {
         unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
     }
+
+    /// Validated UTF-8 view of the bytes pushed so far
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(self.as_slice())
+    }
+
+    /// Bounds-checked view of `range`; `None` if it reaches past `len`
+    pub fn subslice(&self, range: std::ops::Range<usize>) -> Option<&[u8]> {
+        if range.start > range.end || range.end > self.len {
+            return None;
+        }
+        // Safe because start..end lies within the initialized 0..len bytes
+        Some(unsafe { std::slice::from_raw_parts(self.ptr.add(range.start), range.len()) })
+    }
 }
 
 impl Drop for UnsafeBuffer {
@@ -52,6 +66,29 @@ impl Drop for UnsafeBuffer {
     }
 }
 
+/// Drop order made observable
+/// Shows: locals drop in reverse declaration order; a struct's own Drop
+/// runs first, then its fields drop in declaration order
+pub struct Tracked {
+    id: u32,
+    log: std::rc::Rc<std::cell::RefCell<Vec<u32>>>,
+}
+
+impl Tracked {
+    pub fn new(id: u32, log: &std::rc::Rc<std::cell::RefCell<Vec<u32>>>) -> Self {
+        Tracked {
+            id,
+            log: std::rc::Rc::clone(log),
+        }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.id);
+    }
+}
+
 /// Unsafe trait for low-level operations
 /// Shows: unsafe traits, unsafe impl
 pub unsafe trait Zeroable {
@@ -117,6 +154,37 @@ macro_rules! builder {
     };
 }
 
+/// Accessor macro
+/// Shows: generating methods for an existing struct
+///
+/// `macro_rules!` cannot glue identifiers together (that needs a proc macro
+/// or the `paste` crate), so the getter and setter names are spelled out:
+///
+/// ```ignore
+/// make_accessors!(Point { x: i32 => get_x, set_x });
+/// // expands to
+/// impl Point {
+///     pub fn get_x(&self) -> &i32 { &self.x }
+///     pub fn set_x(&mut self, value: i32) { self.x = value; }
+/// }
+/// ```
+#[macro_export]
+macro_rules! make_accessors {
+    ($name:ident { $($field:ident: $ty:ty => $getter:ident, $setter:ident);* $(;)? }) => {
+        impl $name {
+            $(
+                pub fn $getter(&self) -> &$ty {
+                    &self.$field
+                }
+
+                pub fn $setter(&mut self, value: $ty) {
+                    self.$field = value;
+                }
+            )*
+        }
+    };
+}
+
 /// Unsafe transmutation
 /// Shows: mem::transmute, union (safer alternative)
 pub fn bytes_to_u32(bytes: [u8; 4]) -> u32 {
@@ -124,6 +192,26 @@ pub fn bytes_to_u32(bytes: [u8; 4]) -> u32 {
     // Safer than: unsafe { std::mem::transmute::<[u8; 4], u32>(bytes) }
 }
 
+/// Union-based bit reinterpretation
+/// Shows: #[repr(C)] union, reading a field other than the one written
+#[repr(C)]
+union FloatBits {
+    f: f32,
+    i: u32,
+}
+
+pub fn f32_to_bits(f: f32) -> u32 {
+    let bits = FloatBits { f };
+    // Safe because every bit pattern of an f32 is a valid u32
+    unsafe { bits.i }
+}
+
+pub fn bits_to_f32(i: u32) -> f32 {
+    let bits = FloatBits { i };
+    // Safe because every u32 is some f32 (possibly NaN)
+    unsafe { bits.f }
+}
+
 /// Inline assembly (nightly only, shown as concept)
 /// Shows: asm! macro concept
 #[cfg(feature = "nightly")]
@@ -157,6 +245,18 @@ impl<T: Default + Copy, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, CO
         }
     }
 
+    /// Build from a flat slice in row-major order; `None` unless it has ROWS * COLS items
+    pub fn from_row_major(data: &[T]) -> Option<Self> {
+        if data.len() != ROWS * COLS {
+            return None;
+        }
+        let mut matrix = Self::new();
+        for (cell, value) in matrix.data.iter_mut().flatten().zip(data) {
+            *cell = *value;
+        }
+        Some(matrix)
+    }
+
     pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         self.data.get(row)?.get(col)
     }
@@ -170,6 +270,90 @@ impl<T: Default + Copy, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, CO
     }
 }
 
+/// `m[(row, col)]` indexing; panics on out-of-bounds like slice indexing does.
+/// Use `get` for a non-panicking lookup.
+impl<T, const ROWS: usize, const COLS: usize> std::ops::Index<(usize, usize)> for Matrix<T, ROWS, COLS> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < ROWS && col < COLS,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            row, col, ROWS, COLS
+        );
+        &self.data[row][col]
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> std::ops::IndexMut<(usize, usize)> for Matrix<T, ROWS, COLS> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < ROWS && col < COLS,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            row, col, ROWS, COLS
+        );
+        &mut self.data[row][col]
+    }
+}
+
+/// Physical units checked at compile time
+/// Shows: const generic parameters as type-level dimensions
+///
+/// `Quantity<M, S>` is meters^M * seconds^S. Addition only exists between
+/// identical dimensions, so this is rejected by the compiler:
+///
+/// (marked `ignore` because doctests don't run for examples; paste it into
+/// the test module to see the "cannot add" error)
+///
+/// ```ignore
+/// let nonsense = meters(1.0) + seconds(1.0); // Quantity<1, 0> + Quantity<0, 1>
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<const M: i32, const S: i32>(pub f64);
+
+pub fn meters(v: f64) -> Quantity<1, 0> {
+    Quantity(v)
+}
+
+pub fn seconds(v: f64) -> Quantity<0, 1> {
+    Quantity(v)
+}
+
+pub fn hertz(v: f64) -> Quantity<0, -1> {
+    Quantity(v)
+}
+
+impl<const M: i32, const S: i32> std::ops::Add for Quantity<M, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Quantity(self.0 + rhs.0)
+    }
+}
+
+// Stable Rust can't write `Quantity<{ M1 + M2 }, { S1 + S2 }>` yet
+// (generic_const_exprs), so each dimension product is listed explicitly
+macro_rules! impl_quantity_mul {
+    ($(($m1:literal, $s1:literal) * ($m2:literal, $s2:literal) = ($m3:literal, $s3:literal)),* $(,)?) => {
+        $(
+            impl std::ops::Mul<Quantity<$m2, $s2>> for Quantity<$m1, $s1> {
+                type Output = Quantity<$m3, $s3>;
+
+                fn mul(self, rhs: Quantity<$m2, $s2>) -> Quantity<$m3, $s3> {
+                    Quantity(self.0 * rhs.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_quantity_mul! {
+    (1, 0) * (0, -1) = (1, -1),  // meters * 1/seconds = velocity
+    (1, -1) * (0, 1) = (1, 0),   // velocity * seconds = meters
+    (0, 1) * (0, -1) = (0, 0),   // seconds * 1/seconds = dimensionless
+    (1, 0) * (1, 0) = (2, 0),    // meters * meters = area
+}
+
 /// Compile-time assertions
 /// Shows: const_assert macros
 #[macro_export]
@@ -197,6 +381,78 @@ mod tests {
         assert_eq!(buf.as_slice(), &[1, 2, 3]);
     }
 
+    #[test]
+    fn test_unsafe_buffer_as_str() {
+        let mut buf = UnsafeBuffer::with_capacity(4);
+        for byte in "hi".bytes() {
+            buf.push(byte);
+        }
+        assert_eq!(buf.as_str(), Ok("hi"));
+
+        let mut invalid = UnsafeBuffer::with_capacity(4);
+        invalid.push(0xC3); // start of a two-byte sequence...
+        invalid.push(0x28); // ...followed by a non-continuation byte
+        assert!(invalid.as_str().is_err());
+    }
+
+    #[test]
+    fn test_unsafe_buffer_subslice() {
+        let mut buf = UnsafeBuffer::with_capacity(10);
+        for byte in 1..=5 {
+            buf.push(byte);
+        }
+
+        assert_eq!(buf.subslice(1..4), Some(&[2, 3, 4][..]));
+        assert_eq!(buf.subslice(5..5), Some(&[][..]));
+        assert_eq!(buf.subslice(3..6), None);
+        assert_eq!(buf.subslice(8..9), None);
+    }
+
+    #[test]
+    fn test_drop_order() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        {
+            let _a = Tracked::new(1, &log);
+            let _b = Tracked::new(2, &log);
+            {
+                let _inner = Tracked::new(3, &log);
+            }
+            let _c = Tracked::new(4, &log);
+        }
+        assert_eq!(*log.borrow(), vec![3, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_drop_order_composite() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        struct Pair {
+            _first: Tracked,
+            _second: Tracked,
+            outer: Tracked,
+        }
+
+        impl Drop for Pair {
+            fn drop(&mut self) {
+                self.outer.log.borrow_mut().push(0);
+            }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        drop(Pair {
+            _first: Tracked::new(1, &log),
+            _second: Tracked::new(2, &log),
+            outer: Tracked::new(3, &log),
+        });
+
+        // Pair::drop runs before any field is dropped
+        assert_eq!(*log.borrow(), vec![0, 1, 2, 3]);
+    }
+
     #[test]
     fn test_vec_of_strings_macro() {
         let v = vec_of_strings!("a", "b", "c");
@@ -213,6 +469,31 @@ mod tests {
         assert_eq!(map.get("b"), Some(&2));
     }
 
+    #[test]
+    fn test_make_accessors_macro() {
+        struct Account {
+            owner: String,
+            balance: u64,
+        }
+
+        make_accessors!(Account {
+            owner: String => get_owner, set_owner;
+            balance: u64 => get_balance, set_balance;
+        });
+
+        let mut account = Account {
+            owner: "alice".to_string(),
+            balance: 10,
+        };
+        assert_eq!(account.get_owner(), "alice");
+        assert_eq!(*account.get_balance(), 10);
+
+        account.set_owner("bob".to_string());
+        account.set_balance(25);
+        assert_eq!(account.get_owner(), "bob");
+        assert_eq!(*account.get_balance(), 25);
+    }
+
     #[test]
     fn test_bytes_to_u32() {
         let bytes = [0x78, 0x56, 0x34, 0x12];
@@ -220,6 +501,16 @@ mod tests {
         assert_eq!(value, 0x12345678);  // Little endian
     }
 
+    #[test]
+    fn test_union_float_bits() {
+        assert_eq!(f32_to_bits(1.0), 0x3F80_0000);
+        assert_eq!(f32_to_bits(1.0), 1.0f32.to_bits());
+        assert_eq!(f32_to_bits(-2.5), (-2.5f32).to_bits());
+
+        assert_eq!(bits_to_f32(f32_to_bits(1.0)), 1.0);
+        assert_eq!(bits_to_f32(0x3F80_0000), f32::from_bits(0x3F80_0000));
+    }
+
     #[test]
     fn test_matrix() {
         let mut m: Matrix<i32, 3, 3> = Matrix::new();
@@ -228,6 +519,54 @@ mod tests {
         assert_eq!(m.get(3, 3), None);  // Out of bounds
     }
 
+    #[test]
+    fn test_matrix_from_row_major() {
+        let m: Matrix<i32, 2, 3> = Matrix::from_row_major(&[1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.get(0, 0), Some(&1));
+        assert_eq!(m.get(0, 2), Some(&3));
+        assert_eq!(m.get(1, 0), Some(&4));
+        assert_eq!(m.get(1, 2), Some(&6));
+
+        assert!(Matrix::<i32, 2, 3>::from_row_major(&[1, 2, 3]).is_none());
+    }
+
+    #[test]
+    fn test_matrix_index_operators() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::new();
+        m[(1, 2)] = 5;
+        m[(0, 0)] += 2;
+
+        assert_eq!(m[(1, 2)], 5);
+        assert_eq!(m[(0, 0)], 2);
+        assert_eq!(m.get(1, 2), Some(&5));
+    }
+
+    #[test]
+    #[should_panic(expected = "index (2, 0) out of bounds for 2x3 matrix")]
+    fn test_matrix_index_out_of_bounds() {
+        let m: Matrix<i32, 2, 3> = Matrix::new();
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_matrix_index_mut_out_of_bounds() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::new();
+        m[(0, 3)] = 1;
+    }
+
+    #[test]
+    fn test_quantity_units() {
+        let velocity: Quantity<1, -1> = meters(100.0) * hertz(0.5);
+        assert_eq!(velocity, Quantity(50.0));
+
+        let distance: Quantity<1, 0> = velocity * seconds(3.0);
+        assert_eq!(distance + meters(10.0), meters(160.0));
+
+        let area: Quantity<2, 0> = meters(3.0) * meters(4.0);
+        assert_eq!(area.0, 12.0);
+    }
+
     #[test]
     fn test_zeroable_trait() {
         let z: u32 = Zeroable::zeroed();
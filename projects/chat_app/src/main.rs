@@ -4,32 +4,338 @@
 
 use std::io::{BufRead, BufReader, Write};
 use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Instant;
 
-type Clients = Arc<Mutex<Vec<TcpStream>>>;
+static NEXT_CLIENT_ID: AtomicU32 = AtomicU32::new(1);
 
+struct Client {
+    id: u32,
+    name: String,
+    stream: TcpStream,
+}
+
+type Clients = Arc<Mutex<Vec<Client>>>;
+
+/// ลบ client ออกจากรายการเมื่อ guard ถูก drop
+/// ไม่ว่า handle_client จะจบด้วย EOF, error หรือ panic
+struct ClientGuard {
+    id: u32,
+    clients: Clients,
+}
+
+impl Drop for ClientGuard {
+    fn drop(&mut self) {
+        // ถ้า mutex poisoned ก็ยังลบได้ เพราะข้อมูลใน Vec ไม่เสียหาย
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(pos) = clients.iter().position(|client| client.id == self.id) {
+            let client = clients.remove(pos);
+            println!("{} disconnected", client.name);
+        }
+    }
+}
+
+/// ตรวจชื่อที่ client `id` จะใช้: ต้องไม่ว่าง ไม่มีช่องว่าง และไม่ซ้ำกับ client อื่น
+/// (ชื่อเดิมของตัวเองไม่นับว่าซ้ำ)
+fn check_name(clients: &[Client], id: u32, name: &str) -> Result<(), &'static str> {
+    if name.is_empty() {
+        return Err("name cannot be empty");
+    }
+    if name.contains(char::is_whitespace) {
+        return Err("name cannot contain spaces");
+    }
+    if clients.iter().any(|client| client.id != id && client.name == name) {
+        return Err("name already taken");
+    }
+    Ok(())
+}
+
+/// ลงทะเบียน client ใหม่ แล้วคืน guard ที่จะถอนทะเบียนให้เอง
+/// ตรวจชื่อและเพิ่มเข้ารายการภายใต้ lock เดียวกัน สองคนจึงได้ชื่อเดียวกันพร้อมกันไม่ได้
+/// ชื่อใช้ไม่ได้จะคืน error ชนิด `InvalidInput` พร้อมเหตุผล
+fn register_client(stream: &TcpStream, name: &str, clients: &Clients) -> std::io::Result<ClientGuard> {
+    let id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
+    let stream = stream.try_clone()?;
+    let mut list = clients.lock().unwrap();
+    check_name(&list, id, name)
+        .map_err(|reason| std::io::Error::new(std::io::ErrorKind::InvalidInput, reason))?;
+    list.push(Client {
+        id,
+        name: name.to_string(),
+        stream,
+    });
+    drop(list);
+    
+    Ok(ClientGuard {
+        id,
+        clients: Arc::clone(clients),
+    })
+}
+
+/// คำสั่งที่ขึ้นต้นด้วย '/'
+#[derive(Debug, PartialEq)]
+enum ChatCommand {
+    /// /nick <name> เปลี่ยนชื่อ
+    Nick(String),
+    /// /me <action> broadcast เป็นบรรทัดแอคชัน "* name action"
+    Me(String),
+    /// /list ดูรายชื่อคนที่ต่ออยู่
+    List,
+    /// /quit ออกจากห้อง
+    Quit,
+    /// /msg <user> <text> ส่งข้อความส่วนตัว
+    Msg { target: String, body: String },
+    /// คำสั่งที่ไม่รู้จัก หรือใส่ argument ไม่ครบ (เก็บบรรทัดเดิมไว้แจ้ง error)
+    Unknown(String),
+}
+
+/// แปลงบรรทัดเป็นคำสั่ง คืน None ถ้าไม่ได้ขึ้นต้นด้วย '/' (เป็นข้อความธรรมดา)
+fn parse_command(line: &str) -> Option<ChatCommand> {
+    let line = line.trim();
+    let rest = line.strip_prefix('/')?;
+    let (name, arg) = match rest.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (rest, ""),
+    };
+    
+    let command = match (name, arg) {
+        ("nick", name) if !name.is_empty() && !name.contains(char::is_whitespace) => {
+            ChatCommand::Nick(name.to_string())
+        }
+        ("me", action) if !action.is_empty() => ChatCommand::Me(action.to_string()),
+        ("list", "") => ChatCommand::List,
+        ("quit", "") => ChatCommand::Quit,
+        ("msg", arg) => match arg.split_once(char::is_whitespace) {
+            Some((target, body)) => ChatCommand::Msg {
+                target: target.to_string(),
+                body: body.trim().to_string(),
+            },
+            None => ChatCommand::Unknown(line.to_string()),
+        },
+        _ => ChatCommand::Unknown(line.to_string()),
+    };
+    Some(command)
+}
+
+/// บรรทัดนี้ส่งข้อความไปหาคนอื่นหรือไม่ (ข้อความธรรมดา, /me, /nick, /msg)
+/// บรรทัดพวกนี้ต้องใช้ token จาก RateLimiter ส่วนคำสั่งที่ตอบแค่คนส่งไม่ต้องใช้
+fn sends_to_others(command: &Option<ChatCommand>) -> bool {
+    matches!(
+        command,
+        None | Some(ChatCommand::Me(_) | ChatCommand::Nick(_) | ChatCommand::Msg { .. })
+    )
+}
+
+/// เปลี่ยนชื่อของ client ที่มี id นี้ในรายการ ถ้าชื่อใช้ไม่ได้จะคืนเหตุผลและไม่เปลี่ยนอะไร
+fn rename_client(clients: &Clients, id: u32, name: &str) -> Result<(), &'static str> {
+    let mut clients = clients.lock().unwrap();
+    check_name(&clients, id, name)?;
+    if let Some(client) = clients.iter_mut().find(|client| client.id == id) {
+        client.name = name.to_string();
+    }
+    Ok(())
+}
+
+/// รายชื่อคนที่ต่ออยู่ เรียงตามลำดับที่เข้ามา
+fn usernames(clients: &Clients) -> Vec<String> {
+    clients.lock().unwrap().iter().map(|client| client.name.clone()).collect()
+}
+
+/// หา client ที่จะรับข้อความส่วนตัว (ชื่อต้องตรงทุกตัวอักษร)
+/// แยกออกมาเป็นฟังก์ชันบริสุทธิ์เพื่อให้ทดสอบได้โดยไม่ต้องมี socket
+fn resolve_dm<'a>(clients: &'a [Client], target: &str) -> Option<&'a Client> {
+    clients.iter().find(|client| client.name == target)
+}
+
+/// ตอบกลับเฉพาะคนส่ง
+fn reply(stream: &TcpStream, msg: &str) {
+    let _ = writeln!(&*stream, "{}", msg);
+}
+
+/// จัดข้อความที่จะส่งให้ทุกคนเป็น "name: message" (ตัด newline ท้ายออก)
+fn format_broadcast(name: &str, msg: &str) -> String {
+    format!("{}: {}", name, msg.trim_end())
+}
+
+/// ส่งข้อความหนึ่งบรรทัดให้ทุก client ที่ต่ออยู่
+fn broadcast(clients: &Clients, msg: &str) {
+    let line = format!("{}\n", msg);
+    let clients = clients.lock().unwrap();
+    for client in clients.iter() {
+        let _ = (&client.stream).write_all(line.as_bytes());
+    }
+}
+
+/// จำนวนข้อความที่ส่งติดกันได้ทันที (ขนาดถัง)
+const MESSAGE_BURST: f64 = 5.0;
+/// จำนวนข้อความต่อวินาทีที่ส่งได้ต่อเนื่องระยะยาว
+const MESSAGES_PER_SEC: f64 = 1.0;
+
+/// Token bucket: ถังเก็บ token ได้ไม่เกิน `capacity` และเติมเพิ่มตามเวลาที่ผ่านไป
+/// ส่งข้อความหนึ่งครั้งใช้หนึ่ง token ถ้าถังว่างข้อความนั้นถูกทิ้ง
+struct RateLimiter {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    last: Instant,
+}
+
+impl RateLimiter {
+    /// เริ่มต้นด้วยถังเต็ม
+    fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        RateLimiter {
+            tokens: capacity,
+            capacity,
+            refill_per_sec,
+            last: Instant::now(),
+        }
+    }
+    
+    fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+    
+    /// เหมือน `try_acquire` แต่รับเวลาปัจจุบันเข้ามา เพื่อให้ทดสอบด้วยเวลาจำลองได้
+    /// เวลาที่ย้อนหลัง `last` ถือว่าไม่มีเวลาผ่านไป
+    fn try_acquire_at(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last = self.last.max(now);
+        
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// บรรทัดแรกที่ client ส่งมาคือ username ถ้าว่างหรือซ้ำจะตอบเหตุผลกลับไปแล้วรอบรรทัดถัดไป
+/// หลังจากนั้นบรรทัดที่ขึ้นต้นด้วย '/' เป็นคำสั่ง
+/// ที่เหลือถูก broadcast เป็น "username: message"
+/// ทุกบรรทัดที่ส่งถึงคนอื่น (broadcast, /me, /nick, /msg) ถูกจำกัดอัตราต่อ client
+/// ถ้าเกินจะถูกทิ้งพร้อมแจ้งเตือน
 fn handle_client(stream: TcpStream, clients: Clients) {
     let mut reader = BufReader::new(stream.try_clone().unwrap());
     let mut line = String::new();
     
-    {
-        let mut clients = clients.lock().unwrap();
-        clients.push(stream.try_clone().unwrap());
-    }
+    let (mut name, guard) = loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        let name = line.trim().to_string();
+        match register_client(&stream, &name, &clients) {
+            Ok(guard) => break (name, guard),
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidInput => {
+                reply(&stream, &e.to_string());
+            }
+            Err(_) => return,
+        }
+    };
+    let mut limiter = RateLimiter::new(MESSAGE_BURST, MESSAGES_PER_SEC);
     
     loop {
         line.clear();
         match reader.read_line(&mut line) {
             Ok(0) => break,
-            Ok(_) => {
-                let msg = line.clone();
+            Ok(_) => {}
+            Err(_) => break,
+        }
+        
+        let command = parse_command(&line);
+        if sends_to_others(&command) && !limiter.try_acquire() {
+            reply(&stream, "rate limit exceeded, message dropped");
+            continue;
+        }
+        
+        match command {
+            None => broadcast(&clients, &format_broadcast(&name, &line)),
+            Some(ChatCommand::Nick(new_name)) => {
+                match rename_client(&clients, guard.id, &new_name) {
+                    Ok(()) => {
+                        broadcast(&clients, &format!("{} is now known as {}", name, new_name));
+                        name = new_name;
+                    }
+                    Err(reason) => reply(&stream, reason),
+                }
+            }
+            Some(ChatCommand::Me(action)) => broadcast(&clients, &format!("* {} {}", name, action)),
+            Some(ChatCommand::List) => {
+                reply(&stream, &format!("users: {}", usernames(&clients).join(", ")));
+            }
+            Some(ChatCommand::Quit) => break,
+            Some(ChatCommand::Msg { target, body }) => {
                 let clients = clients.lock().unwrap();
-                for mut client in clients.iter() {
-                    let _ = client.write_all(msg.as_bytes());
+                match resolve_dm(&clients, &target) {
+                    Some(client) => reply(&client.stream, &format!("[DM from {}] {}", name, body)),
+                    None => reply(&stream, "no such user"),
                 }
             }
-            Err(_) => break,
+            Some(ChatCommand::Unknown(command)) => {
+                reply(&stream, &format!("unknown command: {}", command));
+            }
+        }
+    }
+    
+    // ถอนทะเบียนก่อน จะได้ไม่ส่งข้อความลาไปหา socket ที่ปิดแล้ว
+    drop(guard);
+    broadcast(&clients, &format!("{} left the chat", name));
+}
+
+/// จำนวน client สูงสุดที่ server รับพร้อมกัน
+const MAX_CLIENTS: usize = 32;
+
+/// ที่นั่งหนึ่งที่ที่จองไว้ให้ connection ที่รับเข้ามา คืนที่นั่งเมื่อถูก drop
+/// (thread ของ client จบ ไม่ว่าจะลงทะเบียนชื่อสำเร็จหรือไม่)
+struct Slot {
+    active: Arc<AtomicUsize>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// ตัดสินว่าจะรับ connection ใหม่หรือไม่ โดยจองที่นั่งจากตัวนับ `active` ทันทีตอนรับ
+/// connection ที่ยังไม่ได้ส่ง username ก็นับด้วย จึงมี connection ค้างไม่เกิน `max_clients`
+/// ถ้าเต็มแล้วจะส่ง "server full" แล้วคืน None (stream ถูก drop = ปิด connection)
+fn admit(
+    stream: TcpStream,
+    active: &Arc<AtomicUsize>,
+    max_clients: usize,
+) -> Option<(TcpStream, Slot)> {
+    let reserved = active.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+        (n < max_clients).then_some(n + 1)
+    });
+    if reserved.is_err() {
+        let _ = (&stream).write_all(b"server full\n");
+        return None;
+    }
+    Some((stream, Slot { active: Arc::clone(active) }))
+}
+
+/// วนรับ connection จาก listener แล้วแยก thread ให้แต่ละ client
+/// thread ถือ `Slot` ไว้จนจบ ที่นั่งจึงว่างเมื่อ connection ปิดเท่านั้น
+fn run(listener: TcpListener, clients: Clients, max_clients: usize) {
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Some((stream, slot)) = admit(stream, &active, max_clients) {
+                    let clients = Arc::clone(&clients);
+                    thread::spawn(move || {
+                        let _slot = slot;
+                        handle_client(stream, clients);
+                    });
+                }
+            }
+            Err(e) => eprintln!("Error: {}", e),
         }
     }
 }
@@ -40,13 +346,320 @@ fn main() {
     
     println!("Chat server running on 127.0.0.1:8080");
     
-    for stream in listener.incoming() {
-        match stream {
-            Ok(stream) => {
-                let clients = Arc::clone(&clients);
-                thread::spawn(move || handle_client(stream, clients));
-            }
-            Err(e) => eprintln!("Error: {}", e),
+    run(listener, clients, MAX_CLIENTS);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn connected_stream(listener: &TcpListener) -> TcpStream {
+        TcpStream::connect(listener.local_addr().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_dropping_guard_removes_only_that_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        
+        let first = register_client(&connected_stream(&listener), "alice", &clients).unwrap();
+        let second = register_client(&connected_stream(&listener), "bob", &clients).unwrap();
+        assert_eq!(clients.lock().unwrap().len(), 2);
+        
+        let second_id = second.id;
+        drop(first);
+        
+        let ids: Vec<u32> = clients.lock().unwrap().iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![second_id]);
+        assert_eq!(clients.lock().unwrap()[0].name, "bob");
+        
+        drop(second);
+        assert!(clients.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_register_rejects_empty_and_taken_names() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        let _alice = register_client(&connected_stream(&listener), "alice", &clients).unwrap();
+        
+        for (name, reason) in [
+            ("", "name cannot be empty"),
+            ("al ice", "name cannot contain spaces"),
+            ("alice", "name already taken"),
+        ] {
+            let err = register_client(&connected_stream(&listener), name, &clients).err().unwrap();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+            assert_eq!(err.to_string(), reason);
         }
+        assert_eq!(usernames(&clients), vec!["alice"]);
+    }
+
+    #[test]
+    fn test_rename_rejects_taken_name() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        let alice = register_client(&connected_stream(&listener), "alice", &clients).unwrap();
+        let _bob = register_client(&connected_stream(&listener), "bob", &clients).unwrap();
+        
+        assert_eq!(rename_client(&clients, alice.id, "bob"), Err("name already taken"));
+        assert_eq!(rename_client(&clients, alice.id, "alice"), Ok(()));
+        assert_eq!(rename_client(&clients, alice.id, "al"), Ok(()));
+        assert_eq!(usernames(&clients), vec!["al", "bob"]);
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("/nick alice\n"), Some(ChatCommand::Nick("alice".to_string())));
+        assert_eq!(parse_command("/me waves hello"), Some(ChatCommand::Me("waves hello".to_string())));
+        assert_eq!(parse_command("/list"), Some(ChatCommand::List));
+        assert_eq!(parse_command("/quit\r\n"), Some(ChatCommand::Quit));
+        assert_eq!(
+            parse_command("/msg bob see you  soon\n"),
+            Some(ChatCommand::Msg {
+                target: "bob".to_string(),
+                body: "see you  soon".to_string(),
+            })
+        );
+        assert_eq!(parse_command("hello /list"), None);
+        assert_eq!(parse_command(""), None);
+    }
+
+    #[test]
+    fn test_parse_command_unknown_or_malformed() {
+        assert_eq!(parse_command("/dance"), Some(ChatCommand::Unknown("/dance".to_string())));
+        assert_eq!(parse_command("/nick"), Some(ChatCommand::Unknown("/nick".to_string())));
+        assert_eq!(parse_command("/nick a b"), Some(ChatCommand::Unknown("/nick a b".to_string())));
+        assert_eq!(parse_command("/me"), Some(ChatCommand::Unknown("/me".to_string())));
+        assert_eq!(parse_command("/msg bob"), Some(ChatCommand::Unknown("/msg bob".to_string())));
+        assert_eq!(parse_command("/list all"), Some(ChatCommand::Unknown("/list all".to_string())));
+    }
+
+    #[test]
+    fn test_sends_to_others() {
+        for line in ["hello", "/me waves", "/nick al", "/msg bob hi"] {
+            assert!(sends_to_others(&parse_command(line)), "{}", line);
+        }
+        for line in ["/list", "/quit", "/dance"] {
+            assert!(!sends_to_others(&parse_command(line)), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_direct_messages_are_rate_limited() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        
+        let watcher = connected_stream(&listener);
+        let (watcher_server_side, _) = listener.accept().unwrap();
+        let watcher_guard = register_client(&watcher_server_side, "watcher", &clients).unwrap();
+        
+        let alice = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let server_clients = Arc::clone(&clients);
+        let handle = thread::spawn(move || handle_client(server_side, server_clients));
+        
+        let burst = MESSAGE_BURST as usize;
+        let script = format!("alice\n{}/quit\n", "/msg watcher hi\n".repeat(burst + 1));
+        (&alice).write_all(script.as_bytes()).unwrap();
+        handle.join().unwrap();
+        
+        let mut replies = String::new();
+        (&alice).read_to_string(&mut replies).unwrap();
+        assert_eq!(replies, "rate limit exceeded, message dropped\n");
+        
+        drop(watcher_guard);
+        drop(watcher_server_side);
+        let mut received = String::new();
+        (&watcher).read_to_string(&mut received).unwrap();
+        assert_eq!(received, "[DM from alice] hi\n".repeat(burst) + "alice left the chat\n");
+    }
+
+    #[test]
+    fn test_resolve_dm() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = |id, name: &str| Client {
+            id,
+            name: name.to_string(),
+            stream: connected_stream(&listener),
+        };
+        let clients = vec![client(1, "alice"), client(2, "bob")];
+        
+        assert_eq!(resolve_dm(&clients, "bob").map(|c| c.id), Some(2));
+        assert!(resolve_dm(&clients, "carol").is_none());
+        assert!(resolve_dm(&clients, "Bob").is_none());
+        assert!(resolve_dm(&[], "alice").is_none());
+    }
+
+    #[test]
+    fn test_admit_rejects_client_over_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_clients = 2;
+        
+        // connection ที่ยังไม่ได้ส่ง username ก็กินที่นั่งแล้ว
+        let _first = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let first_slot = admit(server_side, &active, max_clients).unwrap().1;
+        
+        let _second = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let _second_slot = admit(server_side, &active, max_clients).unwrap().1;
+        
+        let third = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        assert!(admit(server_side, &active, max_clients).is_none());
+        assert_eq!(active.load(Ordering::SeqCst), max_clients);
+        
+        let mut reply = String::new();
+        (&third).read_to_string(&mut reply).unwrap();
+        assert_eq!(reply, "server full\n");
+        
+        // ปิดไปหนึ่ง connection ก็รับคนใหม่ได้
+        drop(first_slot);
+        let _fourth = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        assert!(admit(server_side, &active, max_clients).is_some());
+    }
+
+    #[test]
+    fn test_rate_limiter_burst_then_refill() {
+        use std::time::Duration;
+        
+        let mut limiter = RateLimiter::new(3.0, 2.0);
+        let start = limiter.last;
+        
+        for _ in 0..3 {
+            assert!(limiter.try_acquire_at(start));
+        }
+        assert!(!limiter.try_acquire_at(start));
+        
+        // 2 token ต่อวินาที: ผ่านไป 0.25 วินาทียังไม่ครบหนึ่ง token
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(250)));
+        assert!(limiter.try_acquire_at(start + Duration::from_millis(500)));
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_rate_limiter_caps_at_capacity() {
+        use std::time::Duration;
+        
+        let mut limiter = RateLimiter::new(2.0, 1.0);
+        let start = limiter.last;
+        assert!(limiter.try_acquire_at(start));
+        assert!(limiter.try_acquire_at(start));
+        
+        // ว่างไปนานก็เติมได้แค่เต็มถัง
+        let later = start + Duration::from_secs(60);
+        assert!(limiter.try_acquire_at(later));
+        assert!(limiter.try_acquire_at(later));
+        assert!(!limiter.try_acquire_at(later));
+        
+        // เวลาย้อนหลังไม่ทำให้ได้ token เพิ่มหรือ panic
+        assert!(!limiter.try_acquire_at(start));
+    }
+
+    #[test]
+    fn test_format_broadcast() {
+        assert_eq!(format_broadcast("alice", "hello\n"), "alice: hello");
+        assert_eq!(format_broadcast("bob", "hi there\r\n"), "bob: hi there");
+        assert_eq!(format_broadcast("carol", ""), "carol: ");
+    }
+
+    #[test]
+    fn test_username_join_and_leave() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        
+        // watcher ต่อเข้ามาเพื่อรับ broadcast (ลงทะเบียนฝั่ง server ตรงๆ)
+        let watcher = connected_stream(&listener);
+        let (watcher_server_side, _) = listener.accept().unwrap();
+        let _watcher_guard = register_client(&watcher_server_side, "watcher", &clients).unwrap();
+        
+        let mut alice = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let server_clients = Arc::clone(&clients);
+        let handle = thread::spawn(move || handle_client(server_side, server_clients));
+        
+        alice.write_all(b"alice\nhello\n").unwrap();
+        let mut reader = BufReader::new(watcher);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "alice: hello\n");
+        
+        drop(alice);
+        handle.join().unwrap();
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "alice left the chat\n");
+    }
+
+    #[test]
+    fn test_commands_reply_to_sender_only() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        
+        let watcher = connected_stream(&listener);
+        let (watcher_server_side, _) = listener.accept().unwrap();
+        let _watcher_guard = register_client(&watcher_server_side, "watcher", &clients).unwrap();
+        
+        let alice = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let server_clients = Arc::clone(&clients);
+        let handle = thread::spawn(move || handle_client(server_side, server_clients));
+        
+        let script = "alice\n/list\n/dance\n/msg nobody hi\n/msg watcher psst\n/nick al\n/me waves\n/quit\n";
+        (&alice).write_all(script.as_bytes()).unwrap();
+        let mut alice_reader = BufReader::new(alice.try_clone().unwrap());
+        let mut line = String::new();
+        alice_reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "users: watcher, alice\n");
+        line.clear();
+        alice_reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "unknown command: /dance\n");
+        line.clear();
+        alice_reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "no such user\n");
+        
+        handle.join().unwrap();
+        let mut watcher_reader = BufReader::new(watcher);
+        let expected = [
+            "[DM from alice] psst\n",
+            "alice is now known as al\n",
+            "* al waves\n",
+            "al left the chat\n",
+        ];
+        for want in expected {
+            line.clear();
+            watcher_reader.read_line(&mut line).unwrap();
+            assert_eq!(line, want);
+        }
+    }
+
+    #[test]
+    fn test_invalid_names_reply_to_sender() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+        
+        let watcher = connected_stream(&listener);
+        let (watcher_server_side, _) = listener.accept().unwrap();
+        let _watcher_guard = register_client(&watcher_server_side, "watcher", &clients).unwrap();
+        
+        let alice = connected_stream(&listener);
+        let (server_side, _) = listener.accept().unwrap();
+        let server_clients = Arc::clone(&clients);
+        let handle = thread::spawn(move || handle_client(server_side, server_clients));
+        
+        (&alice).write_all(b"\nwatcher\nalice\n/nick watcher\n/quit\n").unwrap();
+        handle.join().unwrap();
+        
+        let mut replies = String::new();
+        (&alice).read_to_string(&mut replies).unwrap();
+        assert_eq!(replies, "name cannot be empty\nname already taken\nname already taken\n");
+        
+        let mut line = String::new();
+        BufReader::new(watcher).read_line(&mut line).unwrap();
+        assert_eq!(line, "alice left the chat\n");
     }
 }
@@ -2,49 +2,330 @@
 //! 
 //! โปรเจคจบ: แอพจัดการ Todo List ผ่าน Command Line
 
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
 
-#[derive(Debug, Clone)]
+/// ความสำคัญของงาน เรียงจากน้อยไปมาก (Low < Medium < High)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        }
+    }
+    
+    fn parse(s: &str) -> Option<Priority> {
+        match s {
+            "low" => Some(Priority::Low),
+            "medium" => Some(Priority::Medium),
+            "high" => Some(Priority::High),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Task {
     pub id: u32,
     pub description: String,
     pub completed: bool,
+    pub priority: Priority,
+    /// วันครบกำหนด นับเป็นจำนวนวันนับจาก epoch (None = ไม่มีกำหนด)
+    pub due: Option<u32>,
+    /// tag ตัวพิมพ์เล็กทั้งหมด ไม่ซ้ำกัน เรียงตามลำดับที่เพิ่ม
+    pub tags: Vec<String>,
 }
 
 impl Task {
+    /// งานใหม่มีความสำคัญ Medium เป็นค่าเริ่มต้น
     pub fn new(id: u32, description: &str) -> Self {
         Task {
             id,
             description: description.to_string(),
             completed: false,
+            priority: Priority::Medium,
+            due: None,
+            tags: Vec::new(),
         }
     }
     
     pub fn complete(&mut self) {
         self.completed = true;
     }
+    
+    /// แปลงเป็น JSON object เช่น `{"id":1,"description":"...","completed":false}`
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"id\":{},\"description\":\"{}\",\"completed\":{}}}",
+            self.id,
+            escape_json(&self.description),
+            self.completed
+        )
+    }
+}
+
+/// escape ข้อความให้ใส่ใน JSON string ได้ (quote, backslash และ control characters)
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// escape ข้อความสำหรับไฟล์บันทึก: 1 งานต่อ 1 บรรทัด แยก field ด้วย tab
+fn escape_field(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape_field(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn invalid_data(line_no: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", line_no, message),
+    )
+}
+
+/// เรียงงานที่ยังไม่เสร็จก่อนงานที่เสร็จแล้ว จากนั้นเรียงตาม id
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.completed
+            .cmp(&other.completed)
+            .then(self.id.cmp(&other.id))
+            .then_with(|| self.description.cmp(&other.description))
+            .then(self.priority.cmp(&other.priority))
+            .then(self.due.cmp(&other.due))
+            .then_with(|| self.tags.cmp(&other.tags))
+    }
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 pub struct TodoList {
-    tasks: HashMap<u32, Task>,
+    // BTreeMap เก็บงานเรียงตาม id อยู่แล้ว ไม่ต้อง sort ทุกครั้งที่ list
+    tasks: BTreeMap<u32, Task>,
     next_id: u32,
 }
 
 impl TodoList {
     pub fn new() -> Self {
         TodoList {
-            tasks: HashMap::new(),
+            tasks: BTreeMap::new(),
             next_id: 1,
         }
     }
     
+    /// นำเข้างานจากข้อความทีละบรรทัด บรรทัดว่างจะถูกข้าม
+    /// บรรทัดที่ขึ้นต้นด้วย "x " ถือว่าเสร็จแล้ว
+    pub fn from_lines(lines: &[&str]) -> TodoList {
+        let mut todo = TodoList::new();
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match line.strip_prefix("x ") {
+                Some(description) => {
+                    let id = todo.add(description.trim());
+                    todo.complete(id);
+                }
+                None => {
+                    todo.add(line);
+                }
+            }
+        }
+        todo
+    }
+    
+    /// บันทึกลงไฟล์ รูปแบบ:
+    /// บรรทัดแรก `next_id <n>` ตามด้วยงานละบรรทัด
+    /// `<id>\t<0|1>\t<priority>\t<due หรือ ->\t<description>[\t<tag>...]`
+    pub fn save_to_file(&self, path: &str) -> io::Result<()> {
+        let mut out = format!("next_id {}\n", self.next_id);
+        for task in self.iter() {
+            out.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}",
+                task.id,
+                if task.completed { 1 } else { 0 },
+                task.priority.as_str(),
+                task.due.map_or("-".to_string(), |day| day.to_string()),
+                escape_field(&task.description)
+            ));
+            for tag in &task.tags {
+                out.push('\t');
+                out.push_str(&escape_field(tag));
+            }
+            out.push('\n');
+        }
+        fs::write(path, out)
+    }
+    
+    /// โหลดจากไฟล์ที่ `save_to_file` เขียนไว้
+    /// next_id จะถูกปรับให้มากกว่า id ที่มีอยู่เสมอ แม้ไฟล์จะบันทึกค่าต่ำกว่านั้นมา
+    pub fn load_from_file(path: &str) -> io::Result<TodoList> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+        
+        let header = lines.next().ok_or_else(|| invalid_data(1, "empty file"))?;
+        let saved_next_id = header
+            .strip_prefix("next_id ")
+            .and_then(|n| n.trim().parse::<u32>().ok())
+            .ok_or_else(|| invalid_data(1, "expected 'next_id <n>'"))?;
+        
+        let mut todo = TodoList::new();
+        for (index, line) in lines.enumerate() {
+            let line_no = index + 2;
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [id, completed, priority, due, description, ref tags @ ..] = fields[..] else {
+                return Err(invalid_data(line_no, "too few tab-separated fields"));
+            };
+            let id: u32 = id.parse().map_err(|_| invalid_data(line_no, "invalid id"))?;
+            let completed = match completed {
+                "0" => false,
+                "1" => true,
+                _ => return Err(invalid_data(line_no, "completed must be 0 or 1")),
+            };
+            let priority = Priority::parse(priority)
+                .ok_or_else(|| invalid_data(line_no, "unknown priority"))?;
+            let due = match due {
+                "-" => None,
+                day => Some(day.parse().map_err(|_| invalid_data(line_no, "invalid due day"))?),
+            };
+            
+            let mut task = Task::new(id, &unescape_field(description));
+            task.completed = completed;
+            task.priority = priority;
+            task.due = due;
+            task.tags = tags.iter().map(|tag| unescape_field(tag)).collect();
+            todo.tasks.insert(id, task);
+        }
+        
+        let max_id = todo.tasks.keys().next_back().copied().unwrap_or(0);
+        todo.next_id = saved_next_id.max(max_id + 1);
+        Ok(todo)
+    }
+    
     pub fn add(&mut self, description: &str) -> u32 {
+        self.add_with_priority(description, Priority::Medium)
+    }
+    
+    pub fn add_with_priority(&mut self, description: &str, priority: Priority) -> u32 {
         let id = self.next_id;
-        self.tasks.insert(id, Task::new(id, description));
+        let mut task = Task::new(id, description);
+        task.priority = priority;
+        self.tasks.insert(id, task);
         self.next_id += 1;
         id
     }
     
+    /// เพิ่มงานพร้อมวันครบกำหนด (จำนวนวันนับจาก epoch)
+    pub fn add_with_due(&mut self, description: &str, due: u32) -> u32 {
+        let id = self.add(description);
+        self.set_due(id, Some(due));
+        id
+    }
+    
+    /// ตั้งหรือลบวันครบกำหนด คืน false ถ้าไม่มีงาน id นี้
+    pub fn set_due(&mut self, id: u32, due: Option<u32>) -> bool {
+        match self.tasks.get_mut(&id) {
+            Some(task) => {
+                task.due = due;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// ติด tag ให้งาน (เก็บเป็นตัวพิมพ์เล็ก)
+    /// คืน false ถ้าไม่มีงาน id นี้ หรืองานมี tag นี้อยู่แล้ว
+    pub fn add_tag(&mut self, id: u32, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        match self.tasks.get_mut(&id) {
+            Some(task) if !tag.is_empty() && !task.tags.contains(&tag) => {
+                task.tags.push(tag);
+                true
+            }
+            _ => false,
+        }
+    }
+    
+    /// เอา tag ออกจากงาน คืน false ถ้าไม่มีงาน id นี้ หรืองานไม่มี tag นี้
+    pub fn remove_tag(&mut self, id: u32, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        match self.tasks.get_mut(&id) {
+            Some(task) => {
+                let before = task.tags.len();
+                task.tags.retain(|t| *t != tag);
+                task.tags.len() != before
+            }
+            None => false,
+        }
+    }
+    
+    /// คืน false ถ้าไม่มีงาน id นี้
+    pub fn set_priority(&mut self, id: u32, priority: Priority) -> bool {
+        match self.tasks.get_mut(&id) {
+            Some(task) => {
+                task.priority = priority;
+                true
+            }
+            None => false,
+        }
+    }
+    
     pub fn complete(&mut self, id: u32) -> Option<&Task> {
         self.tasks.get_mut(&id).map(|task| {
             task.complete();
@@ -56,12 +337,94 @@ impl TodoList {
         self.tasks.remove(&id)
     }
     
+    /// วนงานทั้งหมดตามลำดับ id
+    pub fn iter(&self) -> impl Iterator<Item = &Task> {
+        self.tasks.values()
+    }
+    
     pub fn list(&self) -> Vec<&Task> {
+        self.iter().collect()
+    }
+    
+    /// งานทั้งหมดเรียงตาม `Ord` ของ Task (pending ก่อน แล้วตาม id)
+    pub fn list_ordered(&self) -> Vec<&Task> {
         let mut tasks: Vec<_> = self.tasks.values().collect();
-        tasks.sort_by_key(|t| t.id);
+        tasks.sort();
         tasks
     }
     
+    /// ค้นหางานที่ description มี query อยู่ (substring, ไม่สนตัวพิมพ์เล็ก-ใหญ่)
+    /// เรียงตาม id; query ว่างจะตรงกับทุกงาน
+    pub fn search(&self, query: &str) -> Vec<&Task> {
+        self.search_where(query, |_| true)
+    }
+    
+    /// เหมือน `search` แต่เฉพาะงานที่ยังไม่เสร็จ
+    pub fn search_pending(&self, query: &str) -> Vec<&Task> {
+        self.search_where(query, |t| !t.completed)
+    }
+    
+    /// เหมือน `search` แต่เฉพาะงานที่เสร็จแล้ว
+    pub fn search_completed(&self, query: &str) -> Vec<&Task> {
+        self.search_where(query, |t| t.completed)
+    }
+    
+    fn search_where(&self, query: &str, keep: impl Fn(&Task) -> bool) -> Vec<&Task> {
+        let query = query.to_lowercase();
+        self.iter()
+            .filter(|t| keep(t) && t.description.to_lowercase().contains(&query))
+            .collect()
+    }
+    
+    /// งานที่มี tag นี้ตรงตัว (ไม่ใช่ substring, ไม่สนตัวพิมพ์) ตามลำดับ id
+    pub fn list_by_tag(&self, tag: &str) -> Vec<&Task> {
+        let tag = tag.trim().to_lowercase();
+        self.iter().filter(|t| t.tags.contains(&tag)).collect()
+    }
+    
+    /// งานทั้งหมดเรียง High ก่อน ถ้าความสำคัญเท่ากันเรียงตาม id
+    pub fn list_by_priority(&self) -> Vec<&Task> {
+        let mut tasks = self.list();
+        tasks.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.id.cmp(&b.id)));
+        tasks
+    }
+    
+    /// งานที่ยังไม่เสร็จและเลยกำหนดแล้ว (due < today) ตามลำดับ id
+    /// งานที่เสร็จแล้วไม่นับว่า overdue แม้จะเลยกำหนด
+    pub fn list_overdue(&self, today: u32) -> Vec<&Task> {
+        self.iter()
+            .filter(|t| !t.completed && t.due.is_some_and(|due| due < today))
+            .collect()
+    }
+    
+    /// สัดส่วนงานที่เสร็จแล้ว (0.0 ถึง 1.0)
+    /// รายการว่างถือว่าเสร็จครบ 1.0 เพราะไม่มีงานค้าง
+    pub fn progress(&self) -> f64 {
+        if self.tasks.is_empty() {
+            return 1.0;
+        }
+        let done = self.iter().filter(|t| t.completed).count();
+        done as f64 / self.tasks.len() as f64
+    }
+    
+    /// แถบความคืบหน้า เช่น `[####----] 50%`
+    pub fn format_progress_bar(&self, width: usize) -> String {
+        let progress = self.progress();
+        let filled = (progress * width as f64).round() as usize;
+        format!(
+            "[{}{}] {:.0}%",
+            "#".repeat(filled),
+            "-".repeat(width - filled),
+            progress * 100.0
+        )
+    }
+    
+    /// JSON array ของงานทั้งหมดตามลำดับ id
+    pub fn to_json_array(&self) -> String {
+        let items: Vec<String> = self.iter().map(Task::to_json).collect();
+        format!("[{}]", items.join(","))
+    }
+    
     pub fn list_completed(&self) -> Vec<&Task> {
         self.tasks
             .values()
@@ -77,9 +440,70 @@ impl TodoList {
     }
 }
 
+fn format_task(task: &Task) -> String {
+    let status = if task.completed { "✓" } else { " " };
+    format!("[{}] {}: {}", status, task.id, task.description)
+}
+
+/// โหมดโต้ตอบ: อ่านคำสั่งทีละบรรทัดจาก input แล้วเขียนผลลง output
+/// รองรับ add <ข้อความ>, list, complete <id>, remove <id>, quit
+/// จบเมื่อเจอ quit หรือ EOF
+pub fn repl<R: BufRead, W: Write>(list: &mut TodoList, input: R, mut output: W) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        let (command, arg) = match line.trim().split_once(' ') {
+            Some((command, arg)) => (command, arg.trim()),
+            None => (line.trim(), ""),
+        };
+        
+        match command {
+            "" => {}
+            "add" if !arg.is_empty() => {
+                let id = list.add(arg);
+                writeln!(output, "Added task {}: {}", id, arg)?;
+            }
+            "add" => writeln!(output, "Usage: add <description>")?,
+            "list" => {
+                if list.tasks.is_empty() {
+                    writeln!(output, "No tasks")?;
+                }
+                for task in list.iter() {
+                    writeln!(output, "{}", format_task(task))?;
+                }
+            }
+            "complete" | "remove" => match arg.parse::<u32>() {
+                Err(_) => writeln!(output, "Invalid id: {}", arg)?,
+                Ok(id) if command == "complete" => match list.complete(id) {
+                    Some(_) => writeln!(output, "Completed task {}", id)?,
+                    None => writeln!(output, "Task {} not found", id)?,
+                },
+                Ok(id) => match list.remove(id) {
+                    Some(_) => writeln!(output, "Removed task {}", id)?,
+                    None => writeln!(output, "Task {} not found", id)?,
+                },
+            },
+            "quit" => {
+                writeln!(output, "Bye!")?;
+                break;
+            }
+            other => writeln!(output, "Unknown command: {}", other)?,
+        }
+    }
+    
+    output.flush()
+}
+
 fn main() {
     let mut todo = TodoList::new();
     
+    if std::env::args().nth(1).as_deref() == Some("repl") {
+        let stdin = io::stdin();
+        if let Err(e) = repl(&mut todo, stdin.lock(), io::stdout()) {
+            eprintln!("Error: {}", e);
+        }
+        return;
+    }
+    
     // Add some tasks
     let id1 = todo.add("Learn Rust basics");
     let id2 = todo.add("Practice ownership");
@@ -87,8 +511,7 @@ fn main() {
     
     println!("=== All Tasks ===");
     for task in todo.list() {
-        let status = if task.completed { "✓" } else { " " };
-        println!("[{}] {}: {}", status, task.id, task.description);
+        println!("{}", format_task(task));
     }
     
     // Complete a task
@@ -96,10 +519,11 @@ fn main() {
     
     println!("\n=== After completing task {} ===", id1);
     for task in todo.list() {
-        let status = if task.completed { "✓" } else { " " };
-        println!("[{}] {}: {}", status, task.id, task.description);
+        println!("{}", format_task(task));
     }
     
+    println!("\nProgress: {}", todo.format_progress_bar(20));
+    
     println!("\n=== Pending Tasks ===");
     for task in todo.list_pending() {
         println!("[ ] {}: {}", task.id, task.description);
@@ -151,4 +575,297 @@ mod tests {
         assert_eq!(todo.list_completed().len(), 1);
         assert_eq!(todo.list_pending().len(), 1);
     }
+
+    #[test]
+    fn test_iter_in_id_order() {
+        let mut todo = TodoList::new();
+        for (id, description) in [(5, "five"), (2, "two"), (9, "nine"), (1, "one")] {
+            todo.tasks.insert(id, Task::new(id, description));
+        }
+        
+        let ids: Vec<u32> = todo.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 2, 5, 9]);
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut todo = TodoList::new();
+        assert_eq!(todo.progress(), 1.0);
+        
+        let id1 = todo.add("Task 1");
+        todo.add("Task 2");
+        todo.add("Task 3");
+        todo.add("Task 4");
+        assert_eq!(todo.progress(), 0.0);
+        
+        todo.complete(id1);
+        assert_eq!(todo.progress(), 0.25);
+    }
+
+    #[test]
+    fn test_format_progress_bar() {
+        let mut todo = TodoList::from_lines(&["x done", "pending"]);
+        assert_eq!(todo.format_progress_bar(8), "[####----] 50%");
+        
+        todo.complete(2);
+        assert_eq!(todo.format_progress_bar(4), "[####] 100%");
+        assert_eq!(TodoList::new().format_progress_bar(0), "[] 100%");
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let todo = TodoList::from_lines(&["x done task", "", "pending task"]);
+        let tasks = todo.list();
+        
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].id, 1);
+        assert_eq!(tasks[0].description, "done task");
+        assert!(tasks[0].completed);
+        assert_eq!(tasks[1].id, 2);
+        assert_eq!(tasks[1].description, "pending task");
+        assert!(!tasks[1].completed);
+    }
+
+    #[test]
+    fn test_list_ordered_pending_first() {
+        let mut todo = TodoList::new();
+        let id1 = todo.add("Task 1");
+        let id2 = todo.add("Task 2");
+        let id3 = todo.add("Task 3");
+        todo.complete(id1);
+        
+        let ids: Vec<u32> = todo.list_ordered().iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![id2, id3, id1]);
+    }
+
+    #[test]
+    fn test_task_to_json() {
+        let mut task = Task::new(1, "Buy milk");
+        assert_eq!(task.to_json(), r#"{"id":1,"description":"Buy milk","completed":false}"#);
+        
+        task.complete();
+        assert!(task.to_json().ends_with(r#""completed":true}"#));
+    }
+
+    #[test]
+    fn test_task_to_json_escapes() {
+        let task = Task::new(2, "Read \"Rust\" book\\ch1\nnotes");
+        assert_eq!(
+            task.to_json(),
+            r#"{"id":2,"description":"Read \"Rust\" book\\ch1\nnotes","completed":false}"#
+        );
+        assert_eq!(escape_json("\u{1}"), "\\u0001");
+    }
+
+    #[test]
+    fn test_to_json_array() {
+        assert_eq!(TodoList::new().to_json_array(), "[]");
+        
+        let todo = TodoList::from_lines(&["x first", "second"]);
+        assert_eq!(
+            todo.to_json_array(),
+            r#"[{"id":1,"description":"first","completed":true},{"id":2,"description":"second","completed":false}]"#
+        );
+    }
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("todo_{}_{}.txt", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_path("round_trip");
+        let mut todo = TodoList::new();
+        todo.add("Learn Rust");
+        let id = todo.add("Tabs\tand\nnewlines \\ slashes");
+        todo.add("Ship it");
+        todo.complete(id);
+        
+        todo.save_to_file(&path).unwrap();
+        let mut loaded = TodoList::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        
+        assert_eq!(loaded.list(), todo.list());
+        assert_eq!(loaded.add("Next"), 4);
+    }
+
+    #[test]
+    fn test_save_and_load_keeps_priority() {
+        let path = temp_path("priority");
+        let mut todo = TodoList::new();
+        todo.add_with_priority("Urgent", Priority::High);
+        let id = todo.add_with_priority("Someday", Priority::Low);
+        todo.set_due(id, Some(20_000));
+        todo.add_tag(id, "home");
+        todo.add_tag(id, "tab\tbed");
+        
+        todo.save_to_file(&path).unwrap();
+        let loaded = TodoList::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        
+        assert_eq!(loaded.list(), todo.list());
+    }
+
+    #[test]
+    fn test_load_recomputes_next_id() {
+        let path = temp_path("next_id");
+        let contents = "next_id 1\n7\t0\tmedium\t-\tLate task\n3\t1\thigh\t-\tEarly task\n";
+        fs::write(&path, contents).unwrap();
+        let mut loaded = TodoList::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        
+        let ids: Vec<u32> = loaded.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![3, 7]);
+        assert_eq!(loaded.add("New"), 8);
+    }
+
+    #[test]
+    fn test_load_rejects_bad_files() {
+        let path = temp_path("bad");
+        fs::write(&path, "next_id 2\nnot-a-task\n").unwrap();
+        let err = TodoList::load_from_file(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 2"));
+        assert!(TodoList::load_from_file(&temp_path("missing")).is_err());
+        
+        // รูปแบบที่มีน้อยกว่า 5 ช่องไม่รองรับ
+        let path = temp_path("short");
+        fs::write(&path, "next_id 2\n1\t0\tmedium\tNo due field\n").unwrap();
+        let err = TodoList::load_from_file(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("too few tab-separated fields"));
+    }
+
+    #[test]
+    fn test_repl_session() {
+        let mut todo = TodoList::new();
+        let input = io::Cursor::new(
+            "add Write tests\nadd Ship it\ncomplete 1\nremove 2\nlist\nquit\nadd ignored\n",
+        );
+        let mut output = Vec::new();
+        
+        repl(&mut todo, input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        
+        assert!(output.contains("Added task 1: Write tests"));
+        assert!(output.contains("Completed task 1"));
+        assert!(output.contains("Removed task 2"));
+        assert!(output.contains("[✓] 1: Write tests"));
+        assert!(output.ends_with("Bye!\n"));
+        assert_eq!(todo.list().len(), 1);
+    }
+
+    #[test]
+    fn test_repl_errors_and_eof() {
+        let mut todo = TodoList::new();
+        let input = io::Cursor::new("complete abc\nremove 7\nfly\n");
+        let mut output = Vec::new();
+        
+        repl(&mut todo, input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        
+        assert_eq!(output, "Invalid id: abc\nTask 7 not found\nUnknown command: fly\n");
+    }
+
+    #[test]
+    fn test_list_by_priority() {
+        let mut todo = TodoList::new();
+        let low = todo.add_with_priority("Tidy desk", Priority::Low);
+        let medium = todo.add("Write docs");
+        let high1 = todo.add_with_priority("Fix outage", Priority::High);
+        let high2 = todo.add_with_priority("Rotate keys", Priority::High);
+        
+        assert_eq!(todo.list()[1].priority, Priority::Medium);
+        
+        let ids: Vec<u32> = todo.list_by_priority().iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![high1, high2, medium, low]);
+        
+        assert!(todo.set_priority(low, Priority::High));
+        assert!(!todo.set_priority(999, Priority::High));
+        
+        let ids: Vec<u32> = todo.list_by_priority().iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![low, high1, high2, medium]);
+    }
+
+    #[test]
+    fn test_list_overdue() {
+        let mut todo = TodoList::new();
+        let late = todo.add_with_due("Pay rent", 100);
+        let done_late = todo.add_with_due("File taxes", 90);
+        let due_today = todo.add_with_due("Call mom", 105);
+        let future = todo.add_with_due("Book trip", 200);
+        let undated = todo.add("Read book");
+        todo.complete(done_late);
+        
+        let ids: Vec<u32> = todo.list_overdue(105).iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![late]);
+        
+        let ids: Vec<u32> = todo.list_overdue(201).iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![late, due_today, future]);
+        
+        assert!(todo.set_due(late, None));
+        assert!(todo.set_due(undated, Some(1)));
+        assert!(!todo.set_due(999, Some(1)));
+        let ids: Vec<u32> = todo.list_overdue(105).iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![undated]);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut todo = TodoList::from_lines(&[
+            "x Learn Rust basics",
+            "Practice OWNERSHIP in rust",
+            "Buy groceries",
+            "Trust the borrow checker",
+        ]);
+        todo.add("rusty bike repair");
+        
+        let ids = |tasks: Vec<&Task>| tasks.iter().map(|t| t.id).collect::<Vec<u32>>();
+        
+        assert_eq!(ids(todo.search("RUST")), vec![1, 2, 4, 5]);
+        assert_eq!(ids(todo.search("ownership")), vec![2]);
+        assert!(todo.search("python").is_empty());
+        assert_eq!(ids(todo.search_pending("rust")), vec![2, 4, 5]);
+        assert_eq!(ids(todo.search_completed("rust")), vec![1]);
+        assert!(todo.search_completed("groceries").is_empty());
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut todo = TodoList::new();
+        let report = todo.add("Write report");
+        let shop = todo.add("Buy groceries");
+        
+        assert!(todo.add_tag(report, "Work"));
+        assert!(!todo.add_tag(report, "work"));
+        assert!(todo.add_tag(report, "urgent"));
+        assert!(todo.add_tag(shop, "home"));
+        assert!(!todo.add_tag(999, "work"));
+        assert_eq!(todo.list()[0].tags, vec!["work", "urgent"]);
+        
+        let ids: Vec<u32> = todo.list_by_tag("WORK").iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![report]);
+        assert!(todo.list_by_tag("wor").is_empty());
+        assert!(todo.list_by_tag("garden").is_empty());
+        
+        assert!(todo.remove_tag(report, "Work"));
+        assert!(!todo.remove_tag(report, "work"));
+        assert!(todo.list_by_tag("work").is_empty());
+        assert_eq!(todo.list()[0].tags, vec!["urgent"]);
+    }
+
+    #[test]
+    fn test_task_ordering() {
+        let pending = Task::new(5, "later id but pending");
+        let mut done = Task::new(1, "earlier id but done");
+        done.complete();
+        
+        assert!(pending < done);
+        assert!(Task::new(1, "a") < Task::new(2, "a"));
+    }
 }
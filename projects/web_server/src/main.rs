@@ -2,38 +2,885 @@
 //! 
 //! HTTP Server แบบง่ายใช้ TcpListener
 
-use std::io::{Read, Write};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
-fn handle_connection(mut stream: TcpStream) {
-    let mut buffer = [0; 1024];
-    stream.read(&mut buffer).unwrap();
+/// Request ที่ parse แล้ว (เฉพาะส่วนที่ server ใช้)
+struct Request {
+    method: String,
+    path: String,
+    version: String,
+    headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// หา header แบบไม่สนตัวพิมพ์เล็ก-ใหญ่ของชื่อ
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// parse request line เช่น "GET /index.html HTTP/1.1" เป็น method, path และ version
+    /// คืน None ถ้าไม่มี method กับ path ส่วน version ถ้าไม่ระบุถือเป็น HTTP/1.0
+    fn from_request_line(line: &str) -> Option<Request> {
+        let mut parts = line.split_whitespace();
+        let method = parts.next()?.to_string();
+        let path = parts.next()?.to_string();
+        let version = parts.next().unwrap_or("HTTP/1.0").to_string();
+        Some(Request {
+            method,
+            path,
+            version,
+            headers: Vec::new(),
+        })
+    }
+    
+    /// header Connection มีผลก่อน ถ้าไม่ส่งมา
+    /// HTTP/1.1 ถือว่า keep-alive ส่วน HTTP/1.0 ถือว่าปิดหลังตอบ
+    fn wants_close(&self) -> bool {
+        match self.header("Connection") {
+            Some(value) if value.eq_ignore_ascii_case("close") => true,
+            Some(value) if value.eq_ignore_ascii_case("keep-alive") => false,
+            _ => self.version != "HTTP/1.1",
+        }
+    }
+}
+
+/// อ่าน request หนึ่งตัวจาก stream
+/// คืน Ok(None) เมื่อเจอ EOF ก่อนเริ่ม request ใหม่
+fn read_request<R: BufRead>(reader: &mut R) -> io::Result<Option<Request>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    
+    let mut request = Request::from_request_line(&line)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed request line"))?;
+    
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = header.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    
+    request.headers = headers;
+    
+    // อ่าน body ทิ้งไป เพื่อให้ request ถัดไปบน connection เดียวกันเริ่มตรงตำแหน่ง
+    let body_len = request
+        .header("Content-Length")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(0);
+    io::copy(&mut reader.take(body_len), &mut io::sink())?;
+    
+    Ok(Some(request))
+}
+
+/// body เป็น byte ดิบ จึงส่งไฟล์ที่ไม่ใช่ UTF-8 (เช่นรูปภาพ) ได้ไม่เพี้ยน
+struct Response {
+    status_line: String,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn new(status_line: &str, body: impl Into<Vec<u8>>) -> Self {
+        Response {
+            status_line: status_line.to_string(),
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
     
-    let get = b"GET / HTTP/1.1\r\n";
+    /// ตัวเลข status จาก status line เช่น "HTTP/1.1 404 NOT FOUND" → 404
+    fn status_code(&self) -> u16 {
+        self.status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse().ok())
+            .unwrap_or(0)
+    }
     
-    let (status_line, content) = if buffer.starts_with(get) {
-        ("HTTP/1.1 200 OK", "Hello, Rust!")
-    } else {
-        ("HTTP/1.1 404 NOT FOUND", "404 Not Found")
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut head = format!("{}\r\n", self.status_line);
+        for (key, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", key, value));
+        }
+        head.push_str(&format!("Content-Length: {}\r\n\r\n", self.body.len()));
+        
+        let mut response = head.into_bytes();
+        response.extend_from_slice(&self.body);
+        response
+    }
+}
+
+/// reason phrase ของ status code ที่ route ตั้งได้ หรือ None ถ้าไม่รองรับ
+fn reason_phrase(code: u16) -> Option<&'static str> {
+    let phrase = match code {
+        200 => "OK",
+        201 => "CREATED",
+        204 => "NO CONTENT",
+        301 => "MOVED PERMANENTLY",
+        302 => "FOUND",
+        304 => "NOT MODIFIED",
+        400 => "BAD REQUEST",
+        401 => "UNAUTHORIZED",
+        403 => "FORBIDDEN",
+        404 => "NOT FOUND",
+        405 => "METHOD NOT ALLOWED",
+        500 => "INTERNAL SERVER ERROR",
+        503 => "SERVICE UNAVAILABLE",
+        _ => return None,
     };
+    Some(phrase)
+}
+
+/// route หนึ่งเส้นทาง ใครจะเขียน route แบบไหนก็ได้ขอแค่ implement trait นี้
+trait Route: Send + Sync {
+    /// route นี้รับ request นี้หรือไม่
+    fn matches(&self, method: &str, path: &str) -> bool;
+    
+    /// สร้าง response จาก request ทั้งก้อน (ใช้ header หรือ method ได้)
+    fn respond(&self, request: &Request) -> Response;
+    
+    /// method ที่ route นี้รับสำหรับ path (ใช้ตอบ 405 พร้อม Allow)
+    /// ค่า default คือ None แปลว่าไม่รู้จัก path นี้
+    fn allowed_method(&self, _path: &str) -> Option<&str> {
+        None
+    }
+}
+
+/// method + path ตรงตัว แล้วส่งต่อให้ function handler
+type RouteFn = fn(&Request) -> Response;
+
+struct FnRoute {
+    method: String,
+    path: String,
+    handler: RouteFn,
+}
+
+impl Route for FnRoute {
+    fn matches(&self, method: &str, path: &str) -> bool {
+        self.method == method && self.path == path
+    }
+    
+    fn respond(&self, request: &Request) -> Response {
+        (self.handler)(request)
+    }
+    
+    fn allowed_method(&self, path: &str) -> Option<&str> {
+        (self.path == path).then_some(self.method.as_str())
+    }
+}
+
+/// route ที่ตอบเนื้อหาเดิมเสมอ เมื่อ method และ path ตรงตัว
+struct StaticRoute {
+    method: String,
+    path: String,
+    status_line: String,
+    body: String,
+}
+
+impl StaticRoute {
+    /// panic ถ้า status ไม่อยู่ในรายการของ `reason_phrase`
+    /// เพื่อให้ผิดตั้งแต่ตอนลงทะเบียน ไม่ใช่ตอบ status line แปลกๆ ออกไป
+    fn new(method: &str, path: &str, status: u16, body: &str) -> Self {
+        let reason = reason_phrase(status)
+            .unwrap_or_else(|| panic!("unsupported status code {}", status));
+        StaticRoute {
+            method: method.to_string(),
+            path: path.to_string(),
+            status_line: format!("HTTP/1.1 {} {}", status, reason),
+            body: body.to_string(),
+        }
+    }
+}
+
+impl Route for StaticRoute {
+    fn matches(&self, method: &str, path: &str) -> bool {
+        self.method == method && self.path == path
+    }
+    
+    fn respond(&self, _request: &Request) -> Response {
+        Response::new(&self.status_line, self.body.as_str())
+    }
+    
+    fn allowed_method(&self, path: &str) -> Option<&str> {
+        (self.path == path).then_some(self.method.as_str())
+    }
+}
+
+/// ลอง route ทีละตัวตามลำดับที่ลงทะเบียน ตัวแรกที่ match ได้ตอบ
+/// ไม่มีตัวไหน match → 404 หรือ 405 พร้อม header Allow ถ้ารู้จัก path แต่ method ผิด
+struct Router {
+    routes: Vec<Box<dyn Route>>,
+}
+
+impl Router {
+    fn new() -> Self {
+        Router { routes: Vec::new() }
+    }
+    
+    /// ลงทะเบียน function handler สำหรับ method + path
+    fn add_route(&mut self, method: &str, path: &str, handler: RouteFn) {
+        self.add(FnRoute {
+            method: method.to_string(),
+            path: path.to_string(),
+            handler,
+        });
+    }
+    
+    /// ลงทะเบียน route แบบใดก็ได้ที่ implement `Route`
+    fn add<R: Route + 'static>(&mut self, route: R) {
+        self.routes.push(Box::new(route));
+    }
+    
+    /// method ทั้งหมดที่ลงทะเบียนไว้สำหรับ path นี้ หรือ None ถ้าไม่รู้จัก path
+    fn allowed_methods(&self, path: &str) -> Option<Vec<&str>> {
+        let methods: Vec<&str> = self
+            .routes
+            .iter()
+            .filter_map(|route| route.allowed_method(path))
+            .collect();
+        (!methods.is_empty()).then_some(methods)
+    }
+    
+    fn dispatch(&self, request: &Request) -> Response {
+        let found = self
+            .routes
+            .iter()
+            .find(|route| route.matches(&request.method, &request.path));
+        if let Some(route) = found {
+            return route.respond(request);
+        }
+        
+        let Some(methods) = self.allowed_methods(&request.path) else {
+            return Response::new("HTTP/1.1 404 NOT FOUND", "404 Not Found");
+        };
+        
+        let mut response = Response::new(
+            "HTTP/1.1 405 METHOD NOT ALLOWED",
+            "405 Method Not Allowed",
+        );
+        response.headers.push(("Allow", methods.join(", ")));
+        response
+    }
+}
+
+fn hello(_: &Request) -> Response {
+    Response::new("HTTP/1.1 200 OK", "Hello, Rust!")
+}
+
+/// route ทั้งหมดของ server
+fn app() -> Router {
+    let mut router = Router::new();
+    router.add_route("GET", "/", hello);
+    router.add(StaticRoute::new("GET", "/health", 200, "ok"));
+    router
+}
+
+/// โฟลเดอร์ไฟล์ static ของ server (relative กับ working directory)
+const STATIC_ROOT: &str = "public";
+
+/// เดา Content-Type จากนามสกุลไฟล์
+fn content_type(path: &str) -> &'static str {
+    match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("txt") => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+/// ตัด query string ออกจาก path เช่น "/style.css?v=1" → "/style.css"
+fn strip_query(path: &str) -> &str {
+    path.split('?').next().unwrap_or("")
+}
+
+/// แปลง path ของ request เป็นไฟล์ใต้ `root` แล้วคืน (status line, body)
+/// - "/" หมายถึง index.html
+/// - path ที่มี ".." ถูกปฏิเสธด้วย 403 เพื่อไม่ให้หลุดออกนอก root
+/// - ไฟล์ไม่มีอยู่ → 404
+fn serve_file(path: &str, root: &str) -> (String, Vec<u8>) {
+    let path = strip_query(path);
+    if path.contains("..") || path.contains('\\') {
+        return ("HTTP/1.1 403 FORBIDDEN".to_string(), b"403 Forbidden".to_vec());
+    }
+    
+    let relative = match path.trim_start_matches('/') {
+        "" => "index.html",
+        relative => relative,
+    };
+    
+    match fs::read(Path::new(root).join(relative)) {
+        Ok(body) => ("HTTP/1.1 200 OK".to_string(), body),
+        Err(_) => ("HTTP/1.1 404 NOT FOUND".to_string(), b"404 Not Found".to_vec()),
+    }
+}
+
+/// สิ่งที่รับ request แล้วคืน response
+trait Handler {
+    fn handle(&self, request: &Request) -> Response;
+}
+
+/// function หรือ closure ธรรมดาก็เป็น Handler ได้
+impl<F: Fn(&Request) -> Response> Handler for F {
+    fn handle(&self, request: &Request) -> Response {
+        self(request)
+    }
+}
+
+impl Handler for Router {
+    fn handle(&self, request: &Request) -> Response {
+        self.dispatch(request)
+    }
+}
+
+/// (method, path, status) ของแต่ละ request
+type LogEntry = (String, String, u16);
+
+/// จำนวน entry ล่าสุดที่ Logging เก็บไว้ให้ดูย้อนหลัง ที่เก่ากว่านี้ถูกทิ้ง
+const LOG_CAPACITY: usize = 1000;
+
+/// ระยะห่างระหว่างการพิมพ์สรุป log ใน main
+const LOG_SUMMARY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Middleware: ห่อ handler อื่นแล้วพิมพ์และบันทึกทุก request ทันทีก่อนส่งต่อ response
+/// พิมพ์ต่อ request จึงเห็น log ทันทีแม้ connection จะยัง keep-alive อยู่
+struct Logging<H> {
+    inner: H,
+    log: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+impl<H: Handler> Logging<H> {
+    /// handle ของ log ที่แชร์กันได้ ใช้ดูย้อนหลังได้แม้ส่ง handler ไปแล้ว
+    fn log(&self) -> Arc<Mutex<VecDeque<LogEntry>>> {
+        Arc::clone(&self.log)
+    }
+}
+
+/// สรุป log เป็นจำนวน request แยกตาม status เช่น "3 requests: 200 x2, 404 x1"
+fn summarize_log(log: &VecDeque<LogEntry>) -> String {
+    let mut counts: BTreeMap<u16, usize> = BTreeMap::new();
+    for (_, _, status) in log {
+        *counts.entry(*status).or_default() += 1;
+    }
+    let counts: Vec<String> = counts
+        .iter()
+        .map(|(status, count)| format!("{} x{}", status, count))
+        .collect();
+    format!("{} requests: {}", log.len(), counts.join(", "))
+}
+
+impl<H: Handler> Handler for Logging<H> {
+    fn handle(&self, request: &Request) -> Response {
+        let response = self.inner.handle(request);
+        let status = response.status_code();
+        println!("{} {} -> {}", request.method, request.path, status);
+        
+        let mut log = self.log.lock().unwrap();
+        log.push_back((request.method.clone(), request.path.clone(), status));
+        if log.len() > LOG_CAPACITY {
+            log.pop_front();
+        }
+        response
+    }
+}
+
+fn with_logging<H: Handler>(handler: H) -> Logging<H> {
+    Logging {
+        inner: handler,
+        log: Arc::new(Mutex::new(VecDeque::new())),
+    }
+}
+
+/// Middleware: ถ้า handler ข้างในตอบ 404 กับ GET ให้ลองหาไฟล์ static แทน
+struct StaticFiles<H> {
+    inner: H,
+    root: String,
+}
+
+impl<H: Handler> Handler for StaticFiles<H> {
+    fn handle(&self, request: &Request) -> Response {
+        let response = self.inner.handle(request);
+        if response.status_code() != 404 || request.method != "GET" {
+            return response;
+        }
+        
+        let (status_line, body) = serve_file(&request.path, &self.root);
+        let mut response = Response::new(&status_line, body);
+        if response.status_code() == 200 {
+            let content_type = content_type(strip_query(&request.path));
+            response.headers.push(("Content-Type", content_type.to_string()));
+        }
+        response
+    }
+}
+
+fn with_static_files<H: Handler>(handler: H, root: &str) -> StaticFiles<H> {
+    StaticFiles {
+        inner: handler,
+        root: root.to_string(),
+    }
+}
+
+/// อ่าน-ตอบ request วนไปบน connection เดียว (keep-alive)
+/// จนกว่าจะเจอ EOF หรือ request ที่ต้องปิด connection (ดู `Request::wants_close`)
+fn serve_connection<S: Read + Write, H: Handler>(stream: &mut S, handler: &H) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
     
-    let response = format!(
-        "{}\r\nContent-Length: {}\r\n\r\n{}",
-        status_line,
-        content.len(),
-        content
-    );
+    while let Some(request) = read_request(&mut reader)? {
+        let response = handler.handle(&request);
+        
+        let stream = reader.get_mut();
+        stream.write_all(&response.to_bytes())?;
+        stream.flush()?;
+        
+        if request.wants_close() {
+            break;
+        }
+    }
     
-    stream.write(response.as_bytes()).unwrap();
-    stream.flush().unwrap();
+    Ok(())
+}
+
+/// connection ที่ไม่ส่งอะไรมาเลยนานเกินนี้จะถูกปิด
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// ตั้ง read timeout แล้วรัน serve_connection
+/// หมดเวลาระหว่างรอ request ถัดไปถือเป็นการปิดปกติ ไม่ใช่ error
+fn handle_connection<H: Handler>(
+    mut stream: TcpStream,
+    handler: &H,
+    idle_timeout: Duration,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(idle_timeout))?;
+    match serve_connection(&mut stream, handler) {
+        Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => Ok(()),
+        result => result,
+    }
 }
 
 fn main() {
     let listener = TcpListener::bind("127.0.0.1:7878").unwrap();
     println!("Server running on http://127.0.0.1:7878");
     
+    let handler = Arc::new(with_logging(with_static_files(app(), STATIC_ROOT)));
+    
+    // พิมพ์สรุปจาก log ล่าสุดเป็นระยะ
+    let log = handler.log();
+    thread::spawn(move || loop {
+        thread::sleep(LOG_SUMMARY_INTERVAL);
+        println!("{}", summarize_log(&log.lock().unwrap()));
+    });
+    
+    // แต่ละ connection ได้ thread ของตัวเอง client ที่ค้าง keep-alive ไว้จึงไม่บล็อกคนอื่น
     for stream in listener.incoming() {
-        let stream = stream.unwrap();
-        handle_connection(stream);
+        let stream: TcpStream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Accept error: {}", e);
+                continue;
+            }
+        };
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, &*handler, IDLE_TIMEOUT) {
+                eprintln!("Connection error: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// stream ในหน่วยความจำ: อ่านจาก input, เขียนลง output
+    struct MockStream {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl MockStream {
+        fn new(input: &str) -> Self {
+            MockStream {
+                input: Cursor::new(input.as_bytes().to_vec()),
+                output: Vec::new(),
+            }
+        }
+
+        fn output(&self) -> String {
+            String::from_utf8_lossy(&self.output).into_owned()
+        }
+    }
+
+    impl Read for MockStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for MockStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_keep_alive_serves_pipelined_requests() {
+        let mut stream = MockStream::new(
+            "GET / HTTP/1.1\r\nHost: localhost\r\n\r\nGET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        );
+        serve_connection(&mut stream, &app()).unwrap();
+        
+        assert_eq!(stream.output().matches("HTTP/1.1 200 OK").count(), 2);
+    }
+
+    #[test]
+    fn test_connection_close_stops_loop() {
+        let mut stream = MockStream::new(
+            "GET / HTTP/1.1\r\nConnection: close\r\n\r\nGET / HTTP/1.1\r\n\r\n",
+        );
+        serve_connection(&mut stream, &app()).unwrap();
+        
+        assert_eq!(stream.output().matches("HTTP/1.1").count(), 1);
+    }
+
+    #[test]
+    fn test_http_1_0_closes_by_default() {
+        let mut stream = MockStream::new("GET / HTTP/1.0\r\n\r\nGET / HTTP/1.0\r\n\r\n");
+        serve_connection(&mut stream, &app()).unwrap();
+        assert_eq!(stream.output().matches("HTTP/1.1 200 OK").count(), 1);
+        
+        let mut stream = MockStream::new(
+            "GET / HTTP/1.0\r\nConnection: keep-alive\r\n\r\nGET / HTTP/1.0\r\n\r\n",
+        );
+        serve_connection(&mut stream, &app()).unwrap();
+        assert_eq!(stream.output().matches("HTTP/1.1 200 OK").count(), 2);
+    }
+
+    #[test]
+    fn test_idle_connection_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server_side, _) = listener.accept().unwrap();
+        
+        // client ต่อค้างไว้โดยไม่ส่งอะไร handle_connection ต้องจบเองเมื่อหมดเวลา
+        let timeout = Duration::from_millis(50);
+        let start = std::time::Instant::now();
+        handle_connection(server_side, &app(), timeout).unwrap();
+        assert!(start.elapsed() >= timeout);
+        drop(client);
+    }
+
+    fn request(method: &str, path: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            version: "HTTP/1.1".to_string(),
+            headers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_get_root_returns_200() {
+        let response = app().dispatch(&request("GET", "/"));
+        assert_eq!(response.status_line, "HTTP/1.1 200 OK");
+        assert_eq!(response.body, b"Hello, Rust!");
+    }
+
+    #[test]
+    fn test_health_route() {
+        let response = app().dispatch(&request("GET", "/health"));
+        assert_eq!(response.status_line, "HTTP/1.1 200 OK");
+        assert_eq!(response.body, b"ok");
+    }
+
+    #[test]
+    fn test_wrong_method_returns_405_with_allow() {
+        let response = app().dispatch(&request("POST", "/"));
+        assert_eq!(response.status_line, "HTTP/1.1 405 METHOD NOT ALLOWED");
+        assert_eq!(response.headers, vec![("Allow", "GET".to_string())]);
+        
+        let bytes = String::from_utf8(response.to_bytes()).unwrap();
+        assert!(bytes.contains("Allow: GET\r\n"));
+    }
+
+    #[test]
+    fn test_allowed_methods() {
+        let mut router = app();
+        router.add(StaticRoute::new("POST", "/", 201, "Created"));
+        assert_eq!(router.allowed_methods("/"), Some(vec!["GET", "POST"]));
+        assert_eq!(router.allowed_methods("/missing"), None);
+    }
+
+    #[test]
+    fn test_unknown_path_returns_404() {
+        let response = app().dispatch(&request("POST", "/missing"));
+        assert_eq!(response.status_line, "HTTP/1.1 404 NOT FOUND");
+    }
+
+    #[test]
+    fn test_from_request_line() {
+        let request = Request::from_request_line("GET /about HTTP/1.1\r\n").unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/about");
+        assert_eq!(request.version, "HTTP/1.1");
+        assert_eq!(Request::from_request_line("GET /").unwrap().version, "HTTP/1.0");
+        assert!(Request::from_request_line("GET").is_none());
+        assert!(Request::from_request_line("\r\n").is_none());
+    }
+
+    #[test]
+    fn test_router_dispatches_registered_routes() {
+        fn about(_: &Request) -> Response {
+            Response::new("HTTP/1.1 200 OK", "About us")
+        }
+        fn create(_: &Request) -> Response {
+            Response::new("HTTP/1.1 201 CREATED", "Created")
+        }
+        
+        let mut router = Router::new();
+        router.add_route("GET", "/about", about);
+        router.add_route("POST", "/items", create);
+        
+        let response = router.dispatch(&request("GET", "/about"));
+        assert_eq!(response.status_line, "HTTP/1.1 200 OK");
+        assert_eq!(response.body, b"About us");
+        
+        let response = router.dispatch(&request("POST", "/items"));
+        assert_eq!(response.status_line, "HTTP/1.1 201 CREATED");
+        assert_eq!(response.body, b"Created");
+        
+        assert_eq!(router.dispatch(&request("GET", "/nowhere")).status_code(), 404);
+        assert_eq!(router.dispatch(&request("GET", "/items")).status_code(), 405);
+    }
+
+    #[test]
+    fn test_router_accepts_custom_routes_in_order() {
+        /// ตอบทุก GET ที่ขึ้นต้นด้วย /echo/ ด้วยส่วนที่เหลือของ path และ header X-Name
+        struct EchoRoute;
+        
+        impl Route for EchoRoute {
+            fn matches(&self, method: &str, path: &str) -> bool {
+                method == "GET" && path.starts_with("/echo/")
+            }
+            
+            fn respond(&self, request: &Request) -> Response {
+                let word = &request.path["/echo/".len()..];
+                let body = match request.header("X-Name") {
+                    Some(name) => format!("{} {}", word, name),
+                    None => word.to_string(),
+                };
+                Response::new("HTTP/1.1 200 OK", body)
+            }
+        }
+        
+        let mut router = Router::new();
+        router.add(StaticRoute::new("GET", "/echo/fixed", 204, ""));
+        router.add(EchoRoute);
+        
+        assert_eq!(router.dispatch(&request("GET", "/echo/hello")).body, b"hello");
+        let mut named = request("GET", "/echo/hi");
+        named.headers.push(("x-name".to_string(), "Ann".to_string()));
+        assert_eq!(router.dispatch(&named).body, b"hi Ann");
+        let fixed = router.dispatch(&request("GET", "/echo/fixed"));
+        assert_eq!(fixed.status_line, "HTTP/1.1 204 NO CONTENT");
+        assert_eq!(router.dispatch(&request("POST", "/echo/hello")).status_code(), 404);
+        assert_eq!(router.dispatch(&request("GET", "/other")).status_code(), 404);
+    }
+
+    #[test]
+    fn test_static_route_status_lines() {
+        let route = StaticRoute::new("GET", "/gone", 503, "down");
+        let response = route.respond(&request("GET", "/gone"));
+        assert_eq!(response.status_line, "HTTP/1.1 503 SERVICE UNAVAILABLE");
+        assert_eq!(response.body, b"down");
+        assert_eq!(reason_phrase(999), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported status code 299")]
+    fn test_static_route_rejects_unknown_status() {
+        StaticRoute::new("GET", "/", 299, "");
+    }
+
+    #[test]
+    fn test_malformed_request_line_closes_connection() {
+        let mut stream = MockStream::new("garbage\r\n\r\n");
+        let err = serve_connection(&mut stream, &app()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(stream.output().is_empty());
+    }
+
+    /// สร้างโฟลเดอร์ชั่วคราวที่มี index.html และ style.css
+    fn static_root(name: &str) -> String {
+        let root = std::env::temp_dir().join(format!("web_static_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("index.html"), "<h1>Home</h1>").unwrap();
+        fs::write(root.join("style.css"), "body {}").unwrap();
+        root.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_serve_file_present_and_missing() {
+        let root = static_root("serve");
+        
+        let (status, body) = serve_file("/style.css", &root);
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(body, b"body {}");
+        
+        let (status, body) = serve_file("/", &root);
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(body, b"<h1>Home</h1>");
+        
+        let (status, body) = serve_file("/missing.txt", &root);
+        assert_eq!(status, "HTTP/1.1 404 NOT FOUND");
+        assert_eq!(body, b"404 Not Found");
+        
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_serve_file_rejects_traversal() {
+        let root = static_root("traversal");
+        
+        for path in ["/../secret.txt", "/css/../../etc/passwd", "/..", "/..\\x"] {
+            let (status, _) = serve_file(path, &root);
+            assert_eq!(status, "HTTP/1.1 403 FORBIDDEN", "path {}", path);
+        }
+        
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(content_type("/index.html"), "text/html; charset=utf-8");
+        assert_eq!(content_type("/a/style.css"), "text/css; charset=utf-8");
+        assert_eq!(content_type("/notes.txt"), "text/plain; charset=utf-8");
+        assert_eq!(content_type("/logo.png"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_static_files_fallback() {
+        let root = static_root("fallback");
+        let handler = with_static_files(app(), &root);
+        
+        let response = handler.handle(&request("GET", "/"));
+        assert_eq!(response.body, b"Hello, Rust!");
+        
+        let response = handler.handle(&request("GET", "/style.css"));
+        assert_eq!(response.body, b"body {}");
+        let css = ("Content-Type", "text/css; charset=utf-8".to_string());
+        assert!(response.headers.contains(&css));
+        
+        assert_eq!(handler.handle(&request("GET", "/nope.txt")).status_code(), 404);
+        assert_eq!(handler.handle(&request("POST", "/style.css")).status_code(), 404);
+        
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_binary_file_round_trips_unchanged() {
+        let root = static_root("binary");
+        let bytes: Vec<u8> = vec![0x89, b'P', b'N', b'G', 0x00, 0xff, 0xfe, 0x80];
+        fs::write(Path::new(&root).join("logo.png"), &bytes).unwrap();
+        
+        let handler = with_static_files(app(), &root);
+        let mut stream = MockStream::new("GET /logo.png?v=2 HTTP/1.1\r\nConnection: close\r\n\r\n");
+        serve_connection(&mut stream, &handler).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        
+        let head = b"Content-Type: application/octet-stream\r\nContent-Length: 8\r\n\r\n";
+        assert!(stream.output.ends_with(&[&head[..], &bytes[..]].concat()));
+    }
+
+    #[test]
+    fn test_static_content_type_ignores_query() {
+        let root = static_root("query");
+        let handler = with_static_files(app(), &root);
+        
+        let response = handler.handle(&request("GET", "/style.css?v=1"));
+        assert_eq!(response.body, b"body {}");
+        let css = ("Content-Type", "text/css; charset=utf-8".to_string());
+        assert!(response.headers.contains(&css));
+        
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_logging_middleware_records_requests() {
+        let hello = |_: &Request| Response::new("HTTP/1.1 200 OK", "hello");
+        let handler = with_logging(hello);
+        
+        assert_eq!(handler.handle(&request("GET", "/")).body, b"hello");
+        handler.handle(&request("POST", "/submit"));
+        
+        let log = handler.log();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                ("GET".to_string(), "/".to_string(), 200),
+                ("POST".to_string(), "/submit".to_string(), 200),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_logging_keeps_only_recent_entries() {
+        let handler = with_logging(app());
+        for _ in 0..LOG_CAPACITY {
+            handler.handle(&request("GET", "/"));
+        }
+        handler.handle(&request("GET", "/last"));
+        
+        let log = handler.log();
+        let log = log.lock().unwrap();
+        assert_eq!(log.len(), LOG_CAPACITY);
+        assert_eq!(log.back().unwrap().1, "/last");
+    }
+
+    #[test]
+    fn test_summarize_log() {
+        let handler = with_logging(app());
+        assert_eq!(summarize_log(&handler.log().lock().unwrap()), "0 requests: ");
+        
+        handler.handle(&request("GET", "/"));
+        handler.handle(&request("POST", "/"));
+        handler.handle(&request("GET", "/"));
+        let log = handler.log();
+        assert_eq!(summarize_log(&log.lock().unwrap()), "3 requests: 200 x2, 405 x1");
+    }
+
+    #[test]
+    fn test_logging_wraps_router_statuses() {
+        let handler = with_logging(app());
+        let mut stream = MockStream::new("GET / HTTP/1.1\r\n\r\nPOST / HTTP/1.1\r\n\r\n");
+        serve_connection(&mut stream, &handler).unwrap();
+        
+        let statuses: Vec<u16> = handler.log().lock().unwrap().iter().map(|e| e.2).collect();
+        assert_eq!(statuses, vec![200, 405]);
     }
 }